@@ -0,0 +1,185 @@
+// This file is a part of the Rust Data Reader Library
+// Copyright 2018 Robert Carson
+//
+//    Licensed under the Apache License, Version 2.0 (the "License");
+//    you may not use this file except in compliance with the License.
+//    You may obtain a copy of the License at
+//
+//        http://www.apache.org/licenses/LICENSE-2.0
+//
+//    Unless required by applicable law or agreed to in writing, software
+//    distributed under the License is distributed on an "AS IS" BASIS,
+//    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//    See the License for the specific language governing permissions and
+//    limitations under the License.
+
+//! The derive macros for the data_reader crate. These are used through data_reader's derive feature
+//! rather than by depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Expr, Fields, Ident, LitInt, LitStr, Token};
+
+///Derives data_reader's FromRow trait for a struct, so each row of our data can be read into it using load_txt_records.
+///Each field of the struct is read in from a column of the data, which can be set with a #[col(...)] attribute on the field.
+///
+/// index - the 0 indexed column that the field is read in from such as #[col(3)]. Fields without an index or name are read in from
+///     the column with the same index as the field's position in the struct.
+///
+/// name - the name of the column that the field is read in from such as #[col(name = "temp")].
+///
+/// default - the value a field takes when it's empty such as #[col(default = 0.0)]. If no value is given the type's Default is used.
+#[proc_macro_derive(FromRow, attributes(col))]
+pub fn derive_from_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match from_row(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+///Everything that can be given within a #[col(...)] attribute
+enum ColArg {
+    Index(LitInt),
+    Name(LitStr),
+    Default(Option<Expr>),
+}
+
+impl Parse for ColArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitInt) {
+            return Ok(ColArg::Index(input.parse()?));
+        }
+        let ident: Ident = input.parse()?;
+        if ident == "name" {
+            input.parse::<Token![=]>()?;
+            Ok(ColArg::Name(input.parse()?))
+        } else if ident == "default" {
+            if input.peek(Token![=]) {
+                input.parse::<Token![=]>()?;
+                Ok(ColArg::Default(Some(input.parse()?)))
+            } else {
+                Ok(ColArg::Default(None))
+            }
+        } else {
+            Err(Error::new(ident.span(), "expected a column index, name = \"...\", or default"))
+        }
+    }
+}
+
+///The column that a field is read in from along with what it's set to when it's empty
+struct FieldCol {
+    column: TokenStream2,
+    default: Option<TokenStream2>,
+}
+
+///Reads in the #[col(...)] attributes of a field. The field's position in the struct is used as its column if none is given.
+fn field_col(field: &syn::Field, position: usize) -> syn::Result<FieldCol> {
+    let mut column = None;
+    let mut default = None;
+    for attr in field.attrs.iter().filter(|x| x.path().is_ident("col")) {
+        let args = attr.parse_args_with(Punctuated::<ColArg, Token![,]>::parse_terminated)?;
+        for arg in args {
+            match arg {
+                ColArg::Index(index) => {
+                    if column.is_some() {
+                        return Err(Error::new(index.span(), "a field can only have one column index or name"));
+                    }
+                    let index: usize = index.base10_parse()?;
+                    column = Some(quote!(::data_reader::reader::Column::Index(#index)));
+                }
+                ColArg::Name(name) => {
+                    if column.is_some() {
+                        return Err(Error::new(name.span(), "a field can only have one column index or name"));
+                    }
+                    column = Some(quote!(::data_reader::reader::Column::Name(::std::string::String::from(#name))));
+                }
+                ColArg::Default(Some(expr)) => default = Some(quote!(#expr)),
+                ColArg::Default(None) => default = Some(quote!(::std::default::Default::default())),
+            }
+        }
+    }
+    Ok(FieldCol {
+        column: column.unwrap_or_else(|| quote!(::data_reader::reader::Column::Index(#position))),
+        default,
+    })
+}
+
+fn from_row(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(input.generics.span(), "FromRow can't be derived for structs with generic parameters"));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => return Err(Error::new(Span::call_site(), "FromRow can only be derived for structs")),
+    };
+    if fields.is_empty() {
+        return Err(Error::new(Span::call_site(), "FromRow can only be derived for structs with at least one field"));
+    }
+
+    let cols = fields.iter().enumerate().map(|(i, field)| field_col(field, i)).collect::<syn::Result<Vec<_>>>()?;
+    let types: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+    let row_fields: Vec<Ident> = (0..types.len()).map(|i| format_ident!("f{}", i)).collect();
+    let indices = 0..types.len();
+    let columns = cols.iter().map(|x| &x.column);
+
+    //Each field of our row is converted as soon as it's been parsed, unless it's empty and has a default.
+    let set_fields = cols.iter().zip(types.iter()).map(|(col, ty)| {
+        let convert = quote!(<#ty as ::data_reader::reader::FromField>::from_field(bytes)?);
+        match &col.default {
+            Some(default) => quote!(if bytes.is_empty() { #default } else { #convert }),
+            None => convert,
+        }
+    });
+
+    let unwrap = quote!(expect("every field of a row is set before the row is read in"));
+    let construct = match fields {
+        Fields::Named(named) => {
+            let idents = named.named.iter().map(|field| &field.ident);
+            quote!(#name { #(#idents: row.#row_fields.#unwrap,)* })
+        }
+        _ => quote!(#name ( #(row.#row_fields.#unwrap,)* )),
+    };
+
+    Ok(quote! {
+        const _: () = {
+            pub struct __FromRowFields {
+                #(#row_fields: ::std::option::Option<#types>,)*
+            }
+
+            impl ::std::default::Default for __FromRowFields {
+                fn default() -> Self {
+                    __FromRowFields {
+                        #(#row_fields: ::std::option::Option::None,)*
+                    }
+                }
+            }
+
+            impl ::data_reader::reader::FromRow for #name {
+                type Row = __FromRowFields;
+
+                fn columns() -> ::std::vec::Vec<::data_reader::reader::Column> {
+                    ::std::vec![#(#columns),*]
+                }
+
+                #[inline(always)]
+                fn set_field(row: &mut Self::Row, index: usize, bytes: &[u8]) -> ::std::result::Result<(), ::data_reader::reader::ReaderError> {
+                    match index {
+                        #(#indices => row.#row_fields = ::std::option::Option::Some(#set_fields),)*
+                        _ => {}
+                    }
+                    ::std::result::Result::Ok(())
+                }
+
+                fn from_row(row: Self::Row) -> Self {
+                    #construct
+                }
+            }
+        };
+    })
+}
@@ -15,10 +15,10 @@
 
 #![doc = include_str!("../README.md")]
 
-#[macro_use]
-extern crate anyhow;
 extern crate lexical;
 
 ///Contains all of the functions related to the different readers that will be generated.
 #[macro_use]
 pub mod reader;
+///Contains the functions that write our results back out to delimited text.
+pub mod writer;
@@ -0,0 +1,225 @@
+// This file is a part of the Rust Data Reader Library
+// Copyright 2018 Robert Carson
+//
+//    Licensed under the Apache License, Version 2.0 (the "License");
+//    you may not use this file except in compliance with the License.
+//    You may obtain a copy of the License at
+//
+//        http://www.apache.org/licenses/LICENSE-2.0
+//
+//    Unless required by applicable law or agreed to in writing, software
+//    distributed under the License is distributed on an "AS IS" BASIS,
+//    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//    See the License for the specific language governing permissions and
+//    limitations under the License.
+use super::*;
+
+use std::sync::Arc;
+
+use arrow_array::types::*;
+use arrow_array::{ArrayRef, BooleanArray, LargeStringArray, PrimitiveArray, RecordBatch, StringArray};
+use arrow_buffer::{Buffer, OffsetBuffer, ScalarBuffer};
+use arrow_schema::{ArrowError, Field, Schema as ArrowSchema};
+
+///A type that the columns of a ReaderResultsCol can be turned into Arrow arrays from. It's implemented for the primitive number
+///types, bool, and String along with the Option of each of them, where a None becomes a null.
+pub trait ArrowColumn: Sized {
+    ///Turns our column major values into an array for each of our num_fields columns, which each have num_lines values.
+    fn into_arrays(values: Vec<Self>, num_lines: usize, num_fields: usize) -> Vec<ArrayRef>;
+}
+
+//The primitive number types share a single buffer between all of their columns, so none of our values are copied.
+macro_rules! arrow_primitive {
+    ($($type:ty => $arrow:ty),*) => {
+        $(
+            impl ArrowColumn for $type {
+                fn into_arrays(values: Vec<Self>, num_lines: usize, num_fields: usize) -> Vec<ArrayRef> {
+                    let buffer = ScalarBuffer::<$type>::from(values);
+                    (0..num_fields)
+                        .map(|icol| Arc::new(PrimitiveArray::<$arrow>::new(buffer.slice(icol * num_lines, num_lines), None)) as ArrayRef)
+                        .collect()
+                }
+            }
+
+            impl ArrowColumn for Option<$type> {
+                fn into_arrays(values: Vec<Self>, num_lines: usize, num_fields: usize) -> Vec<ArrayRef> {
+                    split_columns(values, num_lines, num_fields, |col| Arc::new(PrimitiveArray::<$arrow>::from(col)))
+                }
+            }
+        )*
+    };
+}
+
+arrow_primitive!(
+    i8 => Int8Type, i16 => Int16Type, i32 => Int32Type, i64 => Int64Type,
+    u8 => UInt8Type, u16 => UInt16Type, u32 => UInt32Type, u64 => UInt64Type,
+    f32 => Float32Type, f64 => Float64Type
+);
+
+//Every other type needs its values to be copied into the layout that Arrow uses.
+macro_rules! arrow_other {
+    ($($type:ty => $array:ty),*) => {
+        $(
+            impl ArrowColumn for $type {
+                fn into_arrays(values: Vec<Self>, num_lines: usize, num_fields: usize) -> Vec<ArrayRef> {
+                    split_columns(values, num_lines, num_fields, |col| Arc::new(<$array>::from(col)))
+                }
+            }
+
+            impl ArrowColumn for Option<$type> {
+                fn into_arrays(values: Vec<Self>, num_lines: usize, num_fields: usize) -> Vec<ArrayRef> {
+                    split_columns(values, num_lines, num_fields, |col| Arc::new(<$array>::from(col)))
+                }
+            }
+        )*
+    };
+}
+
+arrow_other!(bool => BooleanArray, String => StringArray);
+
+///Splits up our column major values into a Vec for each column, which is then turned into an array.
+fn split_columns<T, F>(values: Vec<T>, num_lines: usize, num_fields: usize, array: F) -> Vec<ArrayRef>
+where
+    F: Fn(Vec<T>) -> ArrayRef,
+{
+    let mut values = values.into_iter();
+    (0..num_fields).map(|_| array(values.by_ref().take(num_lines).collect())).collect()
+}
+
+///Creates a string array that uses the bytes of a column of a RawReaderResultsCols as its values and the end of each field in
+///index as its offsets, so the fields aren't copied. If a column has more bytes than 32 bit offsets can hold a LargeStringArray is used.
+fn string_array(values: Vec<u8>, index: Vec<usize>) -> Result<ArrayRef, ArrowError> {
+    let values = Buffer::from_vec(values);
+    if values.len() <= i32::MAX as usize {
+        let offsets: Vec<i32> = std::iter::once(0).chain(index.into_iter().map(|x| x as i32)).collect();
+        Ok(Arc::new(StringArray::try_new(OffsetBuffer::new(offsets.into()), values, None)?))
+    } else {
+        let offsets: Vec<i64> = std::iter::once(0).chain(index.into_iter().map(|x| x as i64)).collect();
+        Ok(Arc::new(LargeStringArray::try_new(OffsetBuffer::new(offsets.into()), values, None)?))
+    }
+}
+
+///Collects columns of our results as Arrow arrays, so they can be turned into a single RecordBatch. Each of the results that are
+///added can come from a different load of our data, such as loads with different usecols that read in columns of different types,
+///as long as they all have the same number of lines. The columns of the RecordBatch are in the order that they were added.
+#[derive(Debug, Clone, Default)]
+pub struct ArrowColumns {
+    names: Vec<Option<String>>,
+    arrays: Vec<ArrayRef>,
+}
+
+impl ArrowColumns {
+    pub fn new() -> ArrowColumns {
+        ArrowColumns::default()
+    }
+
+    ///Saves off our arrays along with the names that were read in for them if there are any.
+    fn extend(&mut self, arrays: Vec<ArrayRef>, names: Vec<String>) {
+        let mut names = names.into_iter();
+        self.names.extend(arrays.iter().map(|_| names.next()));
+        self.arrays.extend(arrays);
+    }
+
+    ///Adds the columns of results that were read in by load_txt_* with row_format set to false.
+    pub fn push<T: Clone + ArrowColumn>(&mut self, results: ReaderResultsCol<T>) {
+        let arrays = T::into_arrays(results.results, results.num_lines, results.num_fields);
+        self.extend(arrays, results.names);
+    }
+
+    ///Adds the columns of a RawReaderResultsCols as string columns. The bytes of each column are used as the values of its array
+    ///without being copied. It fails if any of the fields aren't valid UTF-8.
+    pub fn push_raw(&mut self, results: RawReaderResultsCols) -> Result<(), ArrowError> {
+        let arrays = results
+            .results
+            .into_iter()
+            .zip(results.index)
+            .map(|(values, index)| string_array(values, index))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.extend(arrays, results.names);
+        Ok(())
+    }
+
+    ///Adds the columns of a Table, where each column becomes an array of its own type. Columns of chars become string columns,
+    ///since Arrow doesn't have a char type.
+    pub fn push_table(&mut self, table: Table) {
+        let num_lines = table.num_lines;
+        let arrays = table
+            .columns
+            .into_iter()
+            .flat_map(|column| match column {
+                ColumnData::Bool(x) => bool::into_arrays(x, num_lines, 1),
+                ColumnData::Char(x) => String::into_arrays(x.into_iter().map(String::from).collect(), num_lines, 1),
+                ColumnData::I8(x) => i8::into_arrays(x, num_lines, 1),
+                ColumnData::I16(x) => i16::into_arrays(x, num_lines, 1),
+                ColumnData::I32(x) => i32::into_arrays(x, num_lines, 1),
+                ColumnData::I64(x) => i64::into_arrays(x, num_lines, 1),
+                ColumnData::U8(x) => u8::into_arrays(x, num_lines, 1),
+                ColumnData::U16(x) => u16::into_arrays(x, num_lines, 1),
+                ColumnData::U32(x) => u32::into_arrays(x, num_lines, 1),
+                ColumnData::U64(x) => u64::into_arrays(x, num_lines, 1),
+                ColumnData::F32(x) => f32::into_arrays(x, num_lines, 1),
+                ColumnData::F64(x) => f64::into_arrays(x, num_lines, 1),
+                ColumnData::String(x) => String::into_arrays(x, num_lines, 1),
+            })
+            .collect();
+        self.extend(arrays, table.names);
+    }
+
+    ///Creates a RecordBatch from all of the columns that have been added. If names is given it needs to have a name for every column.
+    ///Otherwise, the names that were read in along with each of our results are used, and any columns without a name are called
+    ///column_ followed by their 0 indexed position in the RecordBatch. It fails if our columns don't all have the same number of lines.
+    pub fn into_record_batch(self, names: Option<Vec<String>>) -> Result<RecordBatch, ArrowError> {
+        let names = match names {
+            Some(names) if names.len() != self.arrays.len() => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "{} names were given for {} columns",
+                    names.len(),
+                    self.arrays.len()
+                )));
+            }
+            Some(names) => names,
+            None => self
+                .names
+                .into_iter()
+                .enumerate()
+                .map(|(icol, name)| name.unwrap_or_else(|| format!("column_{}", icol)))
+                .collect(),
+        };
+        let fields: Vec<Field> = names
+            .into_iter()
+            .zip(self.arrays.iter())
+            .map(|(name, array)| Field::new(name, array.data_type().clone(), array.null_count() > 0))
+            .collect();
+        RecordBatch::try_new(Arc::new(ArrowSchema::new(fields)), self.arrays)
+    }
+}
+
+impl<T: Clone + ArrowColumn> ReaderResultsCol<T> {
+    ///Converts our results into a RecordBatch with a column for each of our fields. The primitive number types aren't copied.
+    ///The names of our columns are used if names isn't given, which is the same as for ArrowColumns::into_record_batch.
+    pub fn into_record_batch(self, names: Option<Vec<String>>) -> Result<RecordBatch, ArrowError> {
+        let mut columns = ArrowColumns::new();
+        columns.push(self);
+        columns.into_record_batch(names)
+    }
+}
+
+impl RawReaderResultsCols {
+    ///Converts our results into a RecordBatch of string columns without copying any of our fields. It fails if any of the fields
+    ///aren't valid UTF-8. The names of our columns are used if names isn't given, which is the same as for ArrowColumns::into_record_batch.
+    pub fn into_record_batch(self, names: Option<Vec<String>>) -> Result<RecordBatch, ArrowError> {
+        let mut columns = ArrowColumns::new();
+        columns.push_raw(self)?;
+        columns.into_record_batch(names)
+    }
+}
+
+impl Table {
+    ///Converts our table into a RecordBatch where each column has its own type. The primitive number types aren't copied.
+    ///The names of our columns are used if names isn't given, which is the same as for ArrowColumns::into_record_batch.
+    pub fn into_record_batch(self, names: Option<Vec<String>>) -> Result<RecordBatch, ArrowError> {
+        let mut columns = ArrowColumns::new();
+        columns.push_table(self);
+        columns.into_record_batch(names)
+    }
+}
@@ -0,0 +1,198 @@
+// This file is a part of the Rust Data Reader Library
+// Copyright 2018 Robert Carson
+//
+//    Licensed under the Apache License, Version 2.0 (the "License");
+//    you may not use this file except in compliance with the License.
+//    You may obtain a copy of the License at
+//
+//        http://www.apache.org/licenses/LICENSE-2.0
+//
+//    Unless required by applicable law or agreed to in writing, software
+//    distributed under the License is distributed on an "AS IS" BASIS,
+//    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//    See the License for the specific language governing permissions and
+//    limitations under the License.
+use super::*;
+
+use std::mem;
+
+use super::row_iter::{open_file, RowStream};
+
+///Batches reads in our data a batch of up to n_rows rows at a time, so we never need more memory than a single batch takes up
+///no matter how large the data is. Each batch has the same layout that load_txt_* gives us, so it's a ReaderResultsRow if
+///ReaderParams::row_format is true and a ReaderResultsCol otherwise. Type P determines how the fields are converted to type T
+///just like in TypedReaderResultsRows. The primitive number types use the default of LexicalParse, and any type that supports the
+///FromStr trait can use FromStrParse.
+///
+///Iterating over Batches creates a new set of results for each batch. If the memory of an earlier batch should be reused instead,
+///read_rows and read_cols read the next batch into results that we already have.
+///
+///All of ReaderParams is supported, and row_errors works the same way it does for load_txt_*. So, any lines that were skipped
+///with RowErrors::SkipAndReport are in the rejected lines of the batch they were found in. The rows of any rejected lines and
+///filled fields are the rows within their batch, while the line numbers are still the ones in the data.
+pub struct Batches<T, R: BufRead, P = LexicalParse>
+where
+    P: ParseField<T>,
+{
+    stream: RowStream<R>,
+    n_rows: usize,
+    rows: TypedReaderResultsRows<T, P>,
+    cols: TypedReaderResultsCols<T, P>,
+    done: bool,
+}
+
+impl<T, R, P> Batches<T, R, P>
+where
+    R: BufRead,
+    P: ParseField<T>,
+{
+    ///Creates our batches over any type that supports the BufRead trait, where each batch has up to n_rows rows in it.
+    ///The header lines are skipped and our column names are read in right away, so errors from them are returned here.
+    pub fn new(reader: R, params: &ReaderParams, n_rows: usize) -> Result<Batches<T, R, P>, ReaderError> {
+        if n_rows == 0 {
+            return Err(ReaderError::InvalidParams {
+                field: "n_rows",
+                reason: String::from("needs to be greater than 0"),
+            });
+        }
+        let stream = RowStream::new(reader, params, params.row_errors)?;
+
+        Ok(Batches {
+            rows: TypedReaderResultsRows::new(stream.num_fields(), 0),
+            cols: TypedReaderResultsCols::new(stream.num_fields(), 0),
+            stream,
+            n_rows,
+            done: false,
+        })
+    }
+
+    ///The names of the columns that were read in when ReaderParams::names is used
+    pub fn names(&self) -> &[String] {
+        &self.stream.names
+    }
+
+    ///Reads the next batch into batch in row major order, where everything that was in it beforehand is replaced.
+    ///The memory that batch already has is reused, so reading every batch into the same ReaderResultsRow only ever allocates
+    ///enough memory for the largest batch. It returns false once we've run out of data, in which case batch is left empty.
+    pub fn read_rows(&mut self, batch: &mut ReaderResultsRow<T>) -> Result<bool, ReaderError>
+    where
+        T: Clone,
+    {
+        batch.results.clear();
+        batch.rejected.clear();
+        batch.filled.clear();
+        batch.names.clone_from(&self.stream.names);
+
+        //Our rows are read straight into the memory of our batch
+        mem::swap(&mut self.rows.results, &mut batch.results);
+        mem::swap(&mut self.rows.rejected, &mut batch.rejected);
+        mem::swap(&mut self.rows.filled, &mut batch.filled);
+        self.rows.num_lines = 0;
+        self.rows.set_num_fields(self.stream.num_fields());
+
+        let read = Self::read_batch(&mut self.stream, &mut self.rows, self.n_rows, &mut self.done);
+
+        mem::swap(&mut self.rows.results, &mut batch.results);
+        mem::swap(&mut self.rows.rejected, &mut batch.rejected);
+        mem::swap(&mut self.rows.filled, &mut batch.filled);
+        batch.num_fields = self.rows.num_fields;
+        batch.num_lines = read?;
+
+        Ok((batch.num_lines > 0) | !batch.rejected.is_empty())
+    }
+
+    ///Reads the next batch into batch in column major order, where everything that was in it beforehand is replaced.
+    ///The memory that batch already has is reused along with the memory for each of our columns, so reading every batch into
+    ///the same ReaderResultsCol only ever allocates enough memory for the largest batch. It returns false once we've run out of data,
+    ///in which case batch is left empty.
+    pub fn read_cols(&mut self, batch: &mut ReaderResultsCol<T>) -> Result<bool, ReaderError>
+    where
+        T: Clone,
+    {
+        batch.results.clear();
+        batch.rejected.clear();
+        batch.filled.clear();
+        batch.names.clone_from(&self.stream.names);
+
+        //Each of our columns is read in separately and then laid out one after another in our batch
+        for col in self.cols.results.iter_mut() {
+            col.clear();
+        }
+        mem::swap(&mut self.cols.rejected, &mut batch.rejected);
+        mem::swap(&mut self.cols.filled, &mut batch.filled);
+        self.cols.num_lines = 0;
+        self.cols.set_num_fields(self.stream.num_fields());
+
+        let read = Self::read_batch(&mut self.stream, &mut self.cols, self.n_rows, &mut self.done);
+
+        mem::swap(&mut self.cols.rejected, &mut batch.rejected);
+        mem::swap(&mut self.cols.filled, &mut batch.filled);
+        batch.num_fields = self.cols.num_fields;
+        batch.num_lines = read?;
+        for col in self.cols.results.iter_mut() {
+            batch.results.append(col);
+        }
+
+        Ok((batch.num_lines > 0) | !batch.rejected.is_empty())
+    }
+
+    ///Reads up to n_rows rows into results. Once we've run out of data or rows to read in we can make sure that the parameters
+    ///we provided were valid for it, and we're done after that or after any error.
+    fn read_batch<B: RawReaderParse>(stream: &mut RowStream<R>, results: &mut B, n_rows: usize, done: &mut bool) -> Result<usize, ReaderError> {
+        if *done {
+            return Ok(0);
+        }
+        let num_lines_read = n_rows.min(stream.num_lines_left());
+        let read = if num_lines_read > 0 {
+            stream.read(results, num_lines_read).inspect_err(|_| *done = true)?
+        } else {
+            0
+        };
+        if (read < num_lines_read) | (stream.num_lines_left() == 0) {
+            *done = true;
+            stream.finish()?;
+        }
+        Ok(read)
+    }
+}
+
+impl<T, P> Batches<T, Box<dyn BufRead>, P>
+where
+    P: ParseField<T>,
+{
+    ///Creates our batches over the file at location f. If any of the gzip, zstd, bzip2, or xz features are turned on,
+    ///a compressed file is detected by its magic bytes or file extension and is decompressed as it's being read in.
+    pub fn from_file(f: &str, params: &ReaderParams, n_rows: usize) -> Result<Batches<T, Box<dyn BufRead>, P>, ReaderError> {
+        Batches::new(open_file(f)?, params, n_rows)
+    }
+}
+
+impl<T, R, P> Iterator for Batches<T, R, P>
+where
+    T: Clone + 'static,
+    R: BufRead,
+    P: ParseField<T>,
+{
+    type Item = Result<Box<dyn ReaderResults<T>>, ReaderError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let capacity = self.n_rows.min(self.stream.num_lines_left()) * self.stream.num_fields();
+        let results = Vec::<T>::with_capacity(capacity);
+        if self.stream.params.row_format {
+            let mut batch = ReaderResultsRow { num_fields: 0, num_lines: 0, results, rejected: Vec::new(), names: Vec::new(), filled: Vec::new() };
+            match self.read_rows(&mut batch) {
+                Ok(true) => Some(Ok(Box::new(batch))),
+                Ok(false) => None,
+                Err(err) => Some(Err(err)),
+            }
+        }
+        else {
+            let mut batch = ReaderResultsCol { num_fields: 0, num_lines: 0, results, rejected: Vec::new(), names: Vec::new(), filled: Vec::new() };
+            match self.read_cols(&mut batch) {
+                Ok(true) => Some(Ok(Box::new(batch))),
+                Ok(false) => None,
+                Err(err) => Some(Err(err)),
+            }
+        }
+    }
+}
@@ -0,0 +1,101 @@
+// This file is a part of the Rust Data Reader Library
+// Copyright 2018 Robert Carson
+//
+//    Licensed under the Apache License, Version 2.0 (the "License");
+//    you may not use this file except in compliance with the License.
+//    You may obtain a copy of the License at
+//
+//        http://www.apache.org/licenses/LICENSE-2.0
+//
+//    Unless required by applicable law or agreed to in writing, software
+//    distributed under the License is distributed on an "AS IS" BASIS,
+//    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//    See the License for the specific language governing permissions and
+//    limitations under the License.
+use super::*;
+
+use std::io::BufReader;
+use std::path::Path;
+
+///Plain text can easily start with BZh, so along with the block size digit that follows it the magic bytes of either
+///the first block or the end of the stream need to be there before we say our data is bzip2.
+#[cfg(feature = "bzip2")]
+fn is_bzip2(header: &[u8]) -> bool {
+    const BLOCK_MAGIC: [u8; 6] = [0x31, 0x41, 0x59, 0x26, 0x53, 0x59];
+    const EOS_MAGIC: [u8; 6] = [0x17, 0x72, 0x45, 0x38, 0x50, 0x90];
+    header.len() >= 10
+        && header.starts_with(b"BZh")
+        && (b'1'..=b'9').contains(&header[3])
+        && ((header[4..10] == BLOCK_MAGIC) || (header[4..10] == EOS_MAGIC))
+}
+
+///The compression formats that we're able to stream decompress data from.
+///Each format is only available when its cargo feature is turned on.
+pub(crate) enum Compression {
+    #[cfg(feature = "gzip")]
+    Gzip,
+    #[cfg(feature = "zstd")]
+    Zstd,
+    #[cfg(feature = "bzip2")]
+    Bzip2,
+    #[cfg(feature = "xz")]
+    Xz,
+}
+
+impl Compression {
+    ///Determines what compression format our data is in if any. The magic bytes at the start of the data
+    ///are checked first. If none of those match then we fall back to the file extension.
+    ///
+    ///f - the location of the file
+    ///header - the first few bytes of the file
+    pub(crate) fn detect(f: &str, header: &[u8]) -> Option<Compression> {
+        #[cfg(feature = "gzip")]
+        if header.starts_with(&[0x1f, 0x8b]) {
+            return Some(Compression::Gzip);
+        }
+        #[cfg(feature = "zstd")]
+        if header.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+            return Some(Compression::Zstd);
+        }
+        #[cfg(feature = "bzip2")]
+        if is_bzip2(header) {
+            return Some(Compression::Bzip2);
+        }
+        #[cfg(feature = "xz")]
+        if header.starts_with(&[0xfd, b'7', b'z', b'X', b'Z', 0x00]) {
+            return Some(Compression::Xz);
+        }
+
+        let ext = Path::new(f).extension().and_then(|x| x.to_str()).unwrap_or_default();
+        match ext {
+            #[cfg(feature = "gzip")]
+            "gz" => Some(Compression::Gzip),
+            #[cfg(feature = "zstd")]
+            "zst" => Some(Compression::Zstd),
+            #[cfg(feature = "bzip2")]
+            "bz2" => Some(Compression::Bzip2),
+            #[cfg(feature = "xz")]
+            "xz" => Some(Compression::Xz),
+            _ => None,
+        }
+    }
+
+    ///Wraps our compressed reader in a stream decoder. Since the decoders only support the Read trait
+    ///the decompressed data is buffered back up, so that it can be handed off to the parser.
+    ///Files made up of several concatenated compressed streams are read all the way through.
+    pub(crate) fn decoder<'a, R>(&self, reader: R) -> Result<Box<dyn BufRead + 'a>, ReaderError>
+    where
+        R: BufRead + 'a,
+    {
+        match self {
+            #[cfg(feature = "gzip")]
+            Compression::Gzip => Ok(Box::new(BufReader::with_capacity(BUF_SIZE, flate2::bufread::MultiGzDecoder::new(reader)))),
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => Ok(Box::new(BufReader::with_capacity(BUF_SIZE, zstd::stream::read::Decoder::with_buffer(reader)?))),
+            #[cfg(feature = "bzip2")]
+            Compression::Bzip2 => Ok(Box::new(BufReader::with_capacity(BUF_SIZE, bzip2::bufread::MultiBzDecoder::new(reader)))),
+            #[cfg(feature = "xz")]
+            Compression::Xz => Ok(Box::new(BufReader::with_capacity(BUF_SIZE, xz2::bufread::XzDecoder::new_multi_decoder(reader)))),
+        }
+    }
+}
@@ -16,7 +16,7 @@ use super::*;
 
 ///load_txt_f32 reads in a data file that is made up of f32 type data. If this assumption is not made then the parser will fail
 ///during the conversion between strings to f32. It can also fail in a number of other ways related to invalid parameters or the
-///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the Error type.
+///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
 ///One should therefore check to make sure no errors are obtained when examining the file. If a malformed field is seen the error
 ///does contain information about what line number of the data file has the malformed field.
 ///
@@ -29,13 +29,45 @@ use super::*;
 ///Output -
 ///
 /// A Result type that either contains a ReaderResults structure or an error.
-pub fn load_txt_f32(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<f32>>, Error> {
+pub fn load_txt_f32(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<f32>>, ReaderError> {
     load_text_lexical!(f, params, f32)
 }
 
+///load_reader_f32 is the same as load_txt_f32 except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_reader_f32<R: BufRead>(reader: R, params: &ReaderParams) -> Result<Box<dyn ReaderResults<f32>>, ReaderError> {
+    load_text_lexical!(@reader reader, params, f32)
+}
+
+///load_bytes_f32 is the same as load_txt_f32 except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_bytes_f32(bytes: &[u8], params: &ReaderParams) -> Result<Box<dyn ReaderResults<f32>>, ReaderError> {
+    load_text_lexical!(@bytes bytes, params, f32)
+}
+
 ///load_txt_f64 reads in a data file that is made up of f64 type data. If this assumption is not made then the parser will fail
 ///during the conversion between strings to f64. It can also fail in a number of other ways related to invalid parameters or the
-///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the Error type.
+///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
 ///One should therefore check to make sure no errors are obtained when examining the file. If a malformed field is seen the error
 ///does contain information about what line number of the data file has the malformed field.
 ///
@@ -48,7 +80,38 @@ pub fn load_txt_f32(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResu
 ///Output -
 ///
 /// A Result type that either contains a ReaderResults structure or an error.
-
-pub fn load_txt_f64(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<f64>>, Error> {
+pub fn load_txt_f64(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<f64>>, ReaderError> {
     load_text_lexical!(f, params, f64)
 }
+
+///load_reader_f64 is the same as load_txt_f64 except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_reader_f64<R: BufRead>(reader: R, params: &ReaderParams) -> Result<Box<dyn ReaderResults<f64>>, ReaderError> {
+    load_text_lexical!(@reader reader, params, f64)
+}
+
+///load_bytes_f64 is the same as load_txt_f64 except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_bytes_f64(bytes: &[u8], params: &ReaderParams) -> Result<Box<dyn ReaderResults<f64>>, ReaderError> {
+    load_text_lexical!(@bytes bytes, params, f64)
+}
@@ -16,7 +16,7 @@ use super::*;
 
 ///load_txt_i8 reads in a data file that is made up of i8 type data. If this assumption is not made then the parser will fail
 ///during the conversion between strings to i8. It can also fail in a number of other ways related to invalid parameters or the
-///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the Error type.
+///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
 ///One should therefore check to make sure no errors are obtained when examining the file. If a malformed field is seen the error
 ///does contain information about what line number of the data file has the malformed field.
 ///
@@ -29,13 +29,45 @@ use super::*;
 ///Output -
 ///
 /// A Result type that either contains a ReaderResults structure or an error.
-pub fn load_txt_i8(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<i8>>, Error> {
+pub fn load_txt_i8(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<i8>>, ReaderError> {
     load_text_lexical!(f, params, i8)
 }
 
+///load_reader_i8 is the same as load_txt_i8 except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_reader_i8<R: BufRead>(reader: R, params: &ReaderParams) -> Result<Box<dyn ReaderResults<i8>>, ReaderError> {
+    load_text_lexical!(@reader reader, params, i8)
+}
+
+///load_bytes_i8 is the same as load_txt_i8 except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_bytes_i8(bytes: &[u8], params: &ReaderParams) -> Result<Box<dyn ReaderResults<i8>>, ReaderError> {
+    load_text_lexical!(@bytes bytes, params, i8)
+}
+
 ///load_txt_i16 reads in a data file that is made up of i16 type data. If this assumption is not made then the parser will fail
 ///during the conversion between strings to i16. It can also fail in a number of other ways related to invalid parameters or the
-///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the Error type.
+///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
 ///One should therefore check to make sure no errors are obtained when examining the file. If a malformed field is seen the error
 ///does contain information about what line number of the data file has the malformed field.
 ///
@@ -48,13 +80,45 @@ pub fn load_txt_i8(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResul
 ///Output -
 ///
 /// A Result type that either contains a ReaderResults structure or an error.
-pub fn load_txt_i16(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<i16>>, Error> {
+pub fn load_txt_i16(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<i16>>, ReaderError> {
     load_text_lexical!(f, params, i16)
 }
 
+///load_reader_i16 is the same as load_txt_i16 except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_reader_i16<R: BufRead>(reader: R, params: &ReaderParams) -> Result<Box<dyn ReaderResults<i16>>, ReaderError> {
+    load_text_lexical!(@reader reader, params, i16)
+}
+
+///load_bytes_i16 is the same as load_txt_i16 except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_bytes_i16(bytes: &[u8], params: &ReaderParams) -> Result<Box<dyn ReaderResults<i16>>, ReaderError> {
+    load_text_lexical!(@bytes bytes, params, i16)
+}
+
 ///load_txt_i32 reads in a data file that is made up of i32 type data. If this assumption is not made then the parser will fail
 ///during the conversion between strings to i32. It can also fail in a number of other ways related to invalid parameters or the
-///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the Error type.
+///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
 ///One should therefore check to make sure no errors are obtained when examining the file. If a malformed field is seen the error
 ///does contain information about what line number of the data file has the malformed field.
 ///
@@ -67,13 +131,45 @@ pub fn load_txt_i16(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResu
 ///Output -
 ///
 /// A Result type that either contains a ReaderResults structure or an error.
-pub fn load_txt_i32(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<i32>>, Error> {
+pub fn load_txt_i32(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<i32>>, ReaderError> {
     load_text_lexical!(f, params, i32)
 }
 
+///load_reader_i32 is the same as load_txt_i32 except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_reader_i32<R: BufRead>(reader: R, params: &ReaderParams) -> Result<Box<dyn ReaderResults<i32>>, ReaderError> {
+    load_text_lexical!(@reader reader, params, i32)
+}
+
+///load_bytes_i32 is the same as load_txt_i32 except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_bytes_i32(bytes: &[u8], params: &ReaderParams) -> Result<Box<dyn ReaderResults<i32>>, ReaderError> {
+    load_text_lexical!(@bytes bytes, params, i32)
+}
+
 ///load_txt_i64 reads in a data file that is made up of i64 type data. If this assumption is not made then the parser will fail
 ///during the conversion between strings to i64. It can also fail in a number of other ways related to invalid parameters or the
-///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the Error type.
+///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
 ///One should therefore check to make sure no errors are obtained when examining the file. If a malformed field is seen the error
 ///does contain information about what line number of the data file has the malformed field.
 ///
@@ -86,13 +182,45 @@ pub fn load_txt_i32(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResu
 ///Output -
 ///
 /// A Result type that either contains a ReaderResults structure or an error.
-pub fn load_txt_i64(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<i64>>, Error> {
+pub fn load_txt_i64(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<i64>>, ReaderError> {
     load_text_lexical!(f, params, i64)
 }
 
+///load_reader_i64 is the same as load_txt_i64 except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_reader_i64<R: BufRead>(reader: R, params: &ReaderParams) -> Result<Box<dyn ReaderResults<i64>>, ReaderError> {
+    load_text_lexical!(@reader reader, params, i64)
+}
+
+///load_bytes_i64 is the same as load_txt_i64 except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_bytes_i64(bytes: &[u8], params: &ReaderParams) -> Result<Box<dyn ReaderResults<i64>>, ReaderError> {
+    load_text_lexical!(@bytes bytes, params, i64)
+}
+
 ///load_txt_i128 reads in a data file that is made up of i128 type data. If this assumption is not made then the parser will fail
 ///during the conversion between strings to i128. It can also fail in a number of other ways related to invalid parameters or the
-///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the Error type.
+///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
 ///One should therefore check to make sure no errors are obtained when examining the file. If a malformed field is seen the error
 ///does contain information about what line number of the data file has the malformed field.
 ///
@@ -105,6 +233,38 @@ pub fn load_txt_i64(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResu
 ///Output -
 ///
 /// A Result type that either contains a ReaderResults structure or an error.
-pub fn load_txt_i128(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<i128>>, Error> {
+pub fn load_txt_i128(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<i128>>, ReaderError> {
     load_text_lexical!(f, params, i128)
 }
+
+///load_reader_i128 is the same as load_txt_i128 except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_reader_i128<R: BufRead>(reader: R, params: &ReaderParams) -> Result<Box<dyn ReaderResults<i128>>, ReaderError> {
+    load_text_lexical!(@reader reader, params, i128)
+}
+
+///load_bytes_i128 is the same as load_txt_i128 except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_bytes_i128(bytes: &[u8], params: &ReaderParams) -> Result<Box<dyn ReaderResults<i128>>, ReaderError> {
+    load_text_lexical!(@bytes bytes, params, i128)
+}
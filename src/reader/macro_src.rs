@@ -15,174 +15,110 @@
 
 ///load_txt! reads in a data file that is made up of primitive type data. If this assumption is not made then the parser will fail
 ///during the conversion between &[u8] to primitive. It can also fail in a number of other ways related to invalid parameters or the
-///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the Error type.
+///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
 ///One should therefore check to make sure no errors are obtained when examining the file. If a malformed field is seen the error
 ///does contain information about what line number of the data file has the malformed field.
-///Input - f is simply the location of the file.
+///If a field can't be converted to the type, a ReaderError::Conversion is returned with the row, column, line number, and bytes of the field.
+///Input - f is simply the location of the file, any type that supports BufRead if the @reader form is used,
+///        or a slice of bytes if the @bytes form is used.
 ///        params is ReaderParams structure. An example for what this looks like can be found in the test directory.
 ///        type - the data type we'll be using
 ///Output - A Result type that either contains a ReaderResults structure or an error.
 #[doc(hidden)]
 macro_rules! load_text_lexical {
-    ($f:expr, $params:expr, $type: ident) => {{
+    (@option @parse $parse:ident, $src:expr, $params:expr, $type: ident) => {{
 
+        //Missing fields come back as None rather than failing to convert
         if $params.row_format {
-            //Get the raw results
-            let raw_results = parse_txt::<RawReaderResultsRows>($f, $params)?;
-
-            //We are initializing our ReaderResult structure
-            let num_items = raw_results.index.len();
-
-            let mut results = ReaderResultsRow {
-                num_fields: 0,
-                num_lines: 0,
-                results: Vec::<$type>::with_capacity(num_items + 1),
-            };
-
-            results.num_fields = raw_results.num_fields;
-            results.num_lines = raw_results.num_lines;
-
-            for i in 0..num_items {
-                let j: usize = {
-                    if i == 0 {
-                        0
-                    } else {
-                        raw_results.index[i - 1]
-                    }
-                };
-                let k: usize = raw_results.index[i];
-                assert!(k <= raw_results.results.len());
-                assert!(j < raw_results.results.len());
-                assert!(j < k);
-                let slice = &raw_results.results[j..k];
-                let temp: $type = lexical::parse::<$type, _>(slice).unwrap();
-                results.results.push(temp);
-            }
-            Ok(Box::new(results))
+            let typed_results: TypedReaderResultsRows<Option<$type>, OptionParse> = $parse($src, $params)?;
+            Ok(Box::new(ReaderResultsRow::from(typed_results)))
         }
         else {
-            //Get the raw results
-            let raw_results = parse_txt::<RawReaderResultsCols>($f, $params)?;
-
-            //We are initializing our ReaderResult structure
-            let num_items = raw_results.num_fields * raw_results.num_lines;
-
-            let mut results = ReaderResultsCol {
-                num_fields: 0,
-                num_lines: 0,
-                results: Vec::<$type>::with_capacity(num_items + 1),
-            };
-
-            results.num_fields = raw_results.num_fields;
-            results.num_lines = raw_results.num_lines;
-
-            for icol in 0..results.num_fields {
-                for i in 0..raw_results.index[icol].len() {
-                    let j: usize = {
-                        if i == 0 {
-                            0
-                        } else {
-                            raw_results.index[icol][i - 1]
-                        }
-                    };
-                    let k: usize = raw_results.index[icol][i];
-                    assert!(k <= raw_results.results[icol].len());
-                    assert!(j < raw_results.results[icol].len());
-                    assert!(j < k);
-                    let slice = &raw_results.results[icol][j..k];
-                    let temp: $type = lexical::parse::<$type, _>(slice).unwrap();
-                    results.results.push(temp);
-                }
-            }
-            Ok(Box::new(results))
+            let typed_results: TypedReaderResultsCols<Option<$type>, OptionParse> = $parse($src, $params)?;
+            Ok(Box::new(ReaderResultsCol::from(typed_results)))
         }
     }};
+    (@reader $r:expr, $params:expr, $type: ident) => {
+        load_text_lexical!(@parse parse_reader, $r, $params, $type)
+    };
+    (@bytes $b:expr, $params:expr, $type: ident) => {
+        load_text_lexical!(@parse parse_slice, $b, $params, $type)
+    };
+    (@parse $parse:ident, $src:expr, $params:expr, $type: ident) => {{
+
+        //Each field is converted to our type as soon as it has been parsed,
+        //so the raw bytes of the data never need to be saved off.
+        if $params.row_format {
+            let typed_results: TypedReaderResultsRows<$type> = $parse($src, $params)?;
+            Ok(Box::new(ReaderResultsRow::from(typed_results)))
+        }
+        else {
+            let typed_results: TypedReaderResultsCols<$type> = $parse($src, $params)?;
+            Ok(Box::new(ReaderResultsCol::from(typed_results)))
+        }
+    }};
+    ($f:expr, $params:expr, $type: ident) => {
+        load_text_lexical!(@parse parse_file, $f, $params, $type)
+    };
 }
 
 ///load_txt! reads in a data file that is made up of any type data that supports FromStr trait. If this assumption is not made then the parser will fail
 ///during the conversion between &[u8] to the type. It can also fail in a number of other ways related to invalid parameters or the
-///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the Error type.
+///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
 ///One should therefore check to make sure no errors are obtained when examining the file. If a malformed field is seen the error
 ///does contain information about what line number of the data file has the malformed field.
-///Input - f is simply the location of the file.
+///If a field can't be converted to the type, a ReaderError::Conversion is returned with the row, column, line number, and bytes of the field.
+///Input - f is simply the location of the file. If the data instead lives in any type that supports BufRead
+///        then the form load_text!(@reader reader, params, type) can be used instead. If the data is already in memory
+///        as a slice of bytes then the form load_text!(@bytes bytes, params, type) can be used.
 ///        params is ReaderParams structure. An example for what this looks like can be found in the test directory.
 ///        type - the data type we'll be using
+///        Starting any of these forms with @option, such as load_text!(@option f, params, type) or load_text!(@option @bytes bytes, params, type),
+///        reads the data in as Option<type> instead. Missing fields without a fill value from ReaderParams::missing_values are then None.
 ///Output - A Result type that either contains a ReaderResults structure or an error.
 #[macro_export]
 macro_rules! load_text {
-    ($f:expr, $params:expr, $type: ident) => {{
-
+    (@option @reader $r:expr, $params:expr, $type: ident) => {
+        $crate::load_text!(@option @parse parse_reader, $r, $params, $type)
+    };
+    (@option @bytes $b:expr, $params:expr, $type: ident) => {
+        $crate::load_text!(@option @parse parse_bytes, $b, $params, $type)
+    };
+    (@option @parse $parse:ident, $src:expr, $params:expr, $type: ident) => {{
+
+        //Missing fields come back as None rather than failing to convert
         if $params.row_format {
-            //Get the raw results
-            let raw_results = parse_txt::<RawReaderResultsRows>($f, $params)?;
-
-            //We are initializing our ReaderResult structure
-            let num_items = raw_results.index.len();
-
-            let mut results = ReaderResultsRow {
-                num_fields: 0,
-                num_lines: 0,
-                results: Vec::<$type>::with_capacity(num_items + 1),
-            };
-
-            results.num_fields = raw_results.num_fields;
-            results.num_lines = raw_results.num_lines;
-
-            for i in 0..num_items {
-                let j: usize = {
-                    if i == 0 {
-                        0
-                    } else {
-                        raw_results.index[i - 1]
-                    }
-                };
-                let k: usize = raw_results.index[i];
-                assert!(k <= raw_results.results.len());
-                assert!(j < raw_results.results.len());
-                assert!(j < k);
-                let slice = &raw_results.results[j..k];
-                let temp_str = str::from_utf8(slice)?;
-                let temp = $type::from_str(&temp_str)?;
-                results.results.push(temp);
-            }
-            Ok(Box::new(results))
+            let typed_results: TypedReaderResultsRows<Option<$type>, OptionParse<FromStrParse>> = $parse($src, $params)?;
+            Ok(Box::new(ReaderResultsRow::from(typed_results)))
         }
         else {
-            //Get the raw results
-            let raw_results = parse_txt::<RawReaderResultsCols>($f, $params)?;
-
-            //We are initializing our ReaderResult structure
-            let num_items = raw_results.num_fields * raw_results.num_lines;
-
-            let mut results = ReaderResultsCol {
-                num_fields: 0,
-                num_lines: 0,
-                results: Vec::<$type>::with_capacity(num_items + 1),
-            };
-
-            results.num_fields = raw_results.num_fields;
-            results.num_lines = raw_results.num_lines;
-
-            for icol in 0..results.num_fields {
-                for i in 0..raw_results.index[icol].len() {
-                    let j: usize = {
-                        if i == 0 {
-                            0
-                        } else {
-                            raw_results.index[icol][i - 1]
-                        }
-                    };
-                    let k: usize = raw_results.index[icol][i];
-                    assert!(k <= raw_results.results[icol].len());
-                    assert!(j < raw_results.results[icol].len());
-                    assert!(j < k);
-                    let slice = &raw_results.results[icol][j..k];
-                    let temp_str = str::from_utf8(slice)?;
-                    let temp = $type::from_str(&temp_str)?;
-                    results.results.push(temp);
-                }
-            }
-            Ok(Box::new(results))
+            let typed_results: TypedReaderResultsCols<Option<$type>, OptionParse<FromStrParse>> = $parse($src, $params)?;
+            Ok(Box::new(ReaderResultsCol::from(typed_results)))
+        }
+    }};
+    (@option $f:expr, $params:expr, $type: ident) => {
+        $crate::load_text!(@option @parse parse_txt, $f, $params, $type)
+    };
+    (@reader $r:expr, $params:expr, $type: ident) => {
+        $crate::load_text!(@parse parse_reader, $r, $params, $type)
+    };
+    (@bytes $b:expr, $params:expr, $type: ident) => {
+        $crate::load_text!(@parse parse_bytes, $b, $params, $type)
+    };
+    (@parse $parse:ident, $src:expr, $params:expr, $type: ident) => {{
+
+        //Each field is converted to our type as soon as it has been parsed using the FromStr trait,
+        //so the raw bytes of the data never need to be saved off.
+        if $params.row_format {
+            let typed_results: TypedReaderResultsRows<$type, FromStrParse> = $parse($src, $params)?;
+            Ok(Box::new(ReaderResultsRow::from(typed_results)))
+        }
+        else {
+            let typed_results: TypedReaderResultsCols<$type, FromStrParse> = $parse($src, $params)?;
+            Ok(Box::new(ReaderResultsCol::from(typed_results)))
         }
     }};
+    ($f:expr, $params:expr, $type: ident) => {
+        $crate::load_text!(@parse parse_txt, $f, $params, $type)
+    };
 }
@@ -0,0 +1,78 @@
+// This file is a part of the Rust Data Reader Library
+// Copyright 2018 Robert Carson
+//
+//    Licensed under the Apache License, Version 2.0 (the "License");
+//    you may not use this file except in compliance with the License.
+//    You may obtain a copy of the License at
+//
+//        http://www.apache.org/licenses/LICENSE-2.0
+//
+//    Unless required by applicable law or agreed to in writing, software
+//    distributed under the License is distributed on an "AS IS" BASIS,
+//    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//    See the License for the specific language governing permissions and
+//    limitations under the License.
+use super::*;
+
+use super::parser::get_col;
+
+///The missing values from ReaderParams once the columns have been found and everything has been turned into bytes,
+///so that they can be compared against our fields as they're parsed.
+pub(crate) struct MissingFields {
+    tokens: Vec<Vec<u8>>,
+    fill: Option<Vec<u8>>,
+    columns: Vec<MissingColumn>,
+}
+
+///The missing values for the column with the 1-based field number field
+struct MissingColumn {
+    field: usize,
+    tokens: Vec<Vec<u8>>,
+    fill: Option<Vec<u8>>,
+}
+
+impl MissingFields {
+    ///Sets up our missing values. Columns given by name are looked up in our column names,
+    ///and if a name can't be found an error is returned.
+    pub(crate) fn new(missing: &MissingValues, names: &[String]) -> Result<MissingFields, ReaderError> {
+        let columns = missing
+            .columns
+            .iter()
+            .map(|col| {
+                let field = get_col(&col.column, names, "missing_values")?;
+                Ok(MissingColumn {
+                    field,
+                    tokens: to_bytes(&col.tokens),
+                    fill: col.fill.as_ref().map(|x| x.as_bytes().to_vec()),
+                })
+            })
+            .collect::<Result<Vec<_>, ReaderError>>()?;
+
+        Ok(MissingFields {
+            tokens: to_bytes(&missing.tokens),
+            fill: missing.fill.as_ref().map(|x| x.as_bytes().to_vec()),
+            columns,
+        })
+    }
+
+    ///If the field with the 1-based field number is missing, this returns what it should be filled in with.
+    ///That's empty if there's no fill value for it.
+    #[inline(always)]
+    pub(crate) fn get_fill(&self, field: usize, bytes: &[u8]) -> Option<&[u8]> {
+        let column = self.columns.iter().find(|x| x.field == field);
+        let missing = bytes.is_empty()
+            || self.tokens.iter().any(|x| x == bytes)
+            || column.is_some_and(|x| x.tokens.iter().any(|x| x == bytes));
+        if !missing {
+            return None;
+        }
+        match column.and_then(|x| x.fill.as_ref()).or(self.fill.as_ref()) {
+            Some(fill) => Some(fill),
+            None => Some(&[]),
+        }
+    }
+}
+
+fn to_bytes(tokens: &[String]) -> Vec<Vec<u8>> {
+    tokens.iter().map(|x| x.as_bytes().to_vec()).collect()
+}
@@ -13,8 +13,7 @@
 //    See the License for the specific language governing permissions and
 //    limitations under the License.
 
-use anyhow::Error;
-use std::str::FromStr;
+use std::io::BufRead;
 use std::str;
 
 #[macro_use]
@@ -29,32 +28,183 @@ pub mod int_reader;
 pub mod prim_reader;
 /// Contains various unsigned integer type readers
 pub mod uint_reader;
+/// Contains readers that return Option<T> for data with missing values in it
+pub mod option_reader;
+/// Contains the Table results and readers for data where each column has its own type
+pub mod table;
+/// Contains the FromRow trait and readers for data where each row is read into a struct
+pub mod record_reader;
+/// Contains the Schema that tells us what type each column of our data can be read in as
+pub mod schema;
+/// Contains the serde Deserializer for the rows of a RawReaderResultsRows
+#[cfg(feature = "serde")]
+pub mod serde_reader;
+/// Contains the conversions from our results into ndarray's Array2 along with readers that return one
+#[cfg(feature = "ndarray")]
+pub mod ndarray_reader;
+/// Contains the conversions from our results into nalgebra's DMatrix
+#[cfg(feature = "nalgebra")]
+pub mod nalgebra_reader;
+/// Contains the conversions from our results into Arrow RecordBatches
+#[cfg(feature = "arrow")]
+pub mod arrow_reader;
+/// Contains the loaders that read our data into polars DataFrames
+#[cfg(feature = "polars")]
+pub mod polars_reader;
+/// Contains the RowIter that reads in our data one row at a time
+pub mod row_iter;
+/// Contains the Batches that read in our data a fixed number of rows at a time
+pub mod batch_reader;
 /// Contains the results from parse_txt or load_txt!
 pub mod reader_results;
+/// Contains the errors that can be returned while reading in data
+pub mod reader_error;
 /// Contains the functions that will parse a file and return a RawReaderResults
 pub mod parser;
+/// Contains the functions that will parse a file in parallel and return a RawReaderResults
+#[cfg(feature = "parallel")]
+pub mod parser_par;
 pub(crate) mod parser_core;
+pub(crate) mod missing;
+#[cfg(any(feature = "gzip", feature = "zstd", feature = "bzip2", feature = "xz"))]
+mod compression;
 /// Contains a couple functions that are useful for parsing files
 pub mod parser_utility;
+/// Contains the functions that work out the delimiter, comment character, and column names of our data
+pub mod sniffer;
 
 pub use self::float_reader::*;
 pub use self::int_reader::*;
 pub use self::prim_reader::*;
 pub use self::uint_reader::*;
-pub use self::macro_src::*;
+pub use self::option_reader::*;
+pub use self::table::*;
+pub use self::record_reader::*;
+pub use self::schema::*;
+#[cfg(feature = "derive")]
+pub use data_reader_derive::FromRow;
+#[cfg(feature = "serde")]
+pub use self::serde_reader::*;
+#[cfg(feature = "ndarray")]
+pub use self::ndarray_reader::*;
+#[cfg(feature = "arrow")]
+pub use self::arrow_reader::*;
+#[cfg(feature = "polars")]
+pub use self::polars_reader::*;
+pub use self::row_iter::*;
+pub use self::batch_reader::*;
 pub use self::reader_results::*;
-pub use self::parser::parse_txt;
+pub use self::reader_error::*;
+pub use self::parser::{parse_txt, parse_reader, parse_bytes};
+#[cfg(feature = "parallel")]
+pub use self::parser_par::{par_parse_txt, par_parse_bytes};
 pub use self::parser_utility::*;
+pub use self::sniffer::*;
+
+//The parsers that the load_txt_* and load_bytes_* functions use.
+//With the parallel feature turned on these are swapped out for their parallel versions.
+#[cfg(feature = "parallel")]
+use self::parser_par::{par_parse_txt as parse_file, par_parse_bytes as parse_slice};
+#[cfg(not(feature = "parallel"))]
+use self::parser::{parse_txt as parse_file, parse_bytes as parse_slice};
+//The parsers that load the Fortran ordered arrays, which count the lines of the data first.
+#[cfg(feature = "ndarray")]
+use self::parser::{parse_txt_counted, parse_bytes_counted};
 
 //This value is similar in value to the one found in BurntSushi's CSV buffer size
 //Our's is just 4x as large.
 const BUF_SIZE: usize = 8 * (1 << 12);
 ///The type of delimiter that we can use
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Delimiter {
     WhiteSpace,
     Any(u8),
 }
 
+///What we should do when a line of data can't be read in, because it has the wrong number of fields,
+///ends with a delimiter, or has a field that can't be converted to the type we're loading.
+///
+/// Fail - return the error, which is the default
+///
+/// Skip - skip over the line and keep on reading in the rest of the data
+///
+/// SkipAndReport - the same as Skip except that the skipped lines are saved off in our results along with
+///     the error that caused them to be skipped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowErrors {
+    Fail,
+    Skip,
+    SkipAndReport,
+}
+
+///Where the names of our columns come from when we want them read in from the data.
+///
+/// Header - the first line of data after any skipped header lines, which isn't read in as data, such as `time x y z temperature`
+///
+/// Comment - the commented line right before the first line of data such as `# x y z`, similar to numpy's names=True.
+///     If there are several commented lines before our data the last one is used.
+///
+///The names are split up using our delimiter, and any leading or trailing whitespace is removed from each one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Names {
+    Header,
+    Comment,
+}
+
+///A column we want to use from our data, which can either be given by its 0 indexed position or by its name.
+///Names can only be used if we're reading in the column names with ReaderParams::names.
+///
+///Indices and names can be converted into a Column, so usecols can look like `Some(vec![0.into(), "x".into()])`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Column {
+    Index(usize),
+    Name(String),
+}
+
+impl From<usize> for Column {
+    fn from(index: usize) -> Column {
+        Column::Index(index)
+    }
+}
+
+impl From<&str> for Column {
+    fn from(name: &str) -> Column {
+        Column::Name(String::from(name))
+    }
+}
+
+impl From<String> for Column {
+    fn from(name: String) -> Column {
+        Column::Name(name)
+    }
+}
+
+///The values that mark a field as missing along with what they should be filled in with, similar to numpy's genfromtxt.
+///An empty field is always treated as missing, and with a delimiter other than whitespace empty fields such as the one in `1,,3`
+///count as a field rather than being skipped over.
+///
+/// tokens - the values that mark a field as missing in every column such as `NA`, `nan`, or `--`
+///
+/// fill - an optional value that missing fields in every column are filled in with. It's converted just like any other field would be.
+///     If there's no fill value a missing field is saved off as an empty field.
+///
+/// columns - the missing values and fill values for specific columns. A column's tokens are used along with the tokens for every column,
+///     and a column's fill value is used instead of the fill value for every column.
+#[derive(Debug, Clone, Default)]
+pub struct MissingValues {
+    pub tokens: Vec<String>,
+    pub fill: Option<String>,
+    pub columns: Vec<ColumnMissingValues>,
+}
+
+///The missing values and fill value for a single column, which can be given by its 0 indexed position or by its name.
+#[derive(Debug, Clone)]
+pub struct ColumnMissingValues {
+    pub column: Column,
+    pub tokens: Vec<String>,
+    pub fill: Option<String>,
+}
+
 ///ReaderParams tells us what our reader should be doing.
 ///
 ///delimiter - the delimiter that tells us what our data fields are seperated by
@@ -65,21 +215,44 @@ pub enum Delimiter {
 /// skip_footer - an optional field that tells us whether or not we should skip so many lines that are not
 ///     comment lines from the end of the file
 ///
-/// usecols - an optional field that tells us what column numbers we should be using from the data field
-///     where these values should be >= 0. Values are 0 indexed here.
+/// usecols - an optional field that tells us what columns we should be using from the data field. Columns can be given
+///     by their number where these values should be >= 0 and are 0 indexed here, or by their name if names is used.
 ///
 /// max_rows - an optional field that tells us the maximum number of rows we should use from the file
 /// row_format - a required field that tells us whether or not the file should be read in row major or column major
 ///              Using ..Default::default() it defaults to being true to preserve old behavior of the code.
+///
+/// row_errors - tells us what to do with lines of data that can't be read in. Using ..Default::default() it defaults to
+///              RowErrors::Fail which returns the error. Errors with the parameters themselves or reading the data always fail.
+///
+/// names - an optional field that tells us to read in the names of our columns from the data. The names are saved off in our results
+///     for the columns that were read in.
+///
+/// quote - an optional field that gives the quote character for quoted fields such as `"Smith, J"`, which follow RFC 4180.
+///     Delimiters, comment characters, and newlines within quotes are a part of the field, and a quote within quotes is escaped
+///     by doubling it. The surrounding quotes aren't saved off as a part of the field. Quoted fields can't be split up across threads,
+///     so the parallel parsers read the data on a single thread when this is used.
+///
+/// missing_values - an optional field that tells us which fields are missing and what they should be filled in with.
+///     Our results record which fields were filled in.
+///
+/// dtypes - an optional field that gives the type of each column that's read in when using load_txt_table. If usecols is used the types
+///     are in the same order as usecols, and otherwise they're in the same order as the columns of our data.
 // is_string - an optional field that tells us if the string passed is a string or file
+#[derive(Debug, Clone)]
 pub struct ReaderParams {
     pub comments: Option<u8>,
     pub delimiter: Delimiter,
     pub skip_header: Option<usize>,
     pub skip_footer: Option<usize>,
-    pub usecols: Option<Vec<usize>>,
+    pub usecols: Option<Vec<Column>>,
     pub max_rows: Option<usize>,
     pub row_format: bool,
+    pub row_errors: RowErrors,
+    pub names: Option<Names>,
+    pub quote: Option<u8>,
+    pub missing_values: Option<MissingValues>,
+    pub dtypes: Option<Vec<DataType>>,
     // pub is_string: Option<bool>,
 }
 
@@ -102,6 +275,11 @@ impl Default for ReaderParams {
             usecols: None,
             max_rows: None,
             row_format: true,
+            row_errors: RowErrors::Fail,
+            names: None,
+            quote: None,
+            missing_values: None,
+            dtypes: None,
             // is_string: None,
         }
     }
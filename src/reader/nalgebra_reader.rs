@@ -0,0 +1,51 @@
+// This file is a part of the Rust Data Reader Library
+// Copyright 2018 Robert Carson
+//
+//    Licensed under the Apache License, Version 2.0 (the "License");
+//    you may not use this file except in compliance with the License.
+//    You may obtain a copy of the License at
+//
+//        http://www.apache.org/licenses/LICENSE-2.0
+//
+//    Unless required by applicable law or agreed to in writing, software
+//    distributed under the License is distributed on an "AS IS" BASIS,
+//    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//    See the License for the specific language governing permissions and
+//    limitations under the License.
+use super::*;
+
+use nalgebra::{DMatrix, Scalar};
+
+///Makes sure that our results have a value for every field of every line before they're turned into a matrix,
+///since nalgebra panics if they don't.
+fn check_shape(num_lines: usize, num_fields: usize, len: usize) -> Result<(), ReaderError> {
+    if num_lines * num_fields != len {
+        return Err(ReaderError::InvalidParams {
+            field: "results",
+            reason: format!("contains {} values but {} lines of {} fields need {} values", len, num_lines, num_fields, num_lines * num_fields),
+        });
+    }
+    Ok(())
+}
+
+impl<T: Scalar> ReaderResultsRow<T> {
+    ///Converts our results into a DMatrix with a row for each line of data and a column for each field.
+    ///A DMatrix is stored in column major order, so our row major results are read in as the transpose of our matrix
+    ///which is then transposed. This copies our data once. Reading in our data with row_format set to false and
+    ///using ReaderResultsCol::into_dmatrix avoids the copy.
+    ///It only fails if results doesn't have num_lines * num_fields values in it.
+    pub fn into_dmatrix(self) -> Result<DMatrix<T>, ReaderError> {
+        check_shape(self.num_lines, self.num_fields, self.results.len())?;
+        Ok(DMatrix::from_vec(self.num_fields, self.num_lines, self.results).transpose())
+    }
+}
+
+impl<T: Scalar> ReaderResultsCol<T> {
+    ///Converts our results into a DMatrix with a row for each line of data and a column for each field.
+    ///Our results are already in column major order, so they become the storage of our matrix without being copied.
+    ///It only fails if results doesn't have num_lines * num_fields values in it.
+    pub fn into_dmatrix(self) -> Result<DMatrix<T>, ReaderError> {
+        check_shape(self.num_lines, self.num_fields, self.results.len())?;
+        Ok(DMatrix::from_vec(self.num_lines, self.num_fields, self.results))
+    }
+}
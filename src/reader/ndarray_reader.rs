@@ -0,0 +1,129 @@
+// This file is a part of the Rust Data Reader Library
+// Copyright 2018 Robert Carson
+//
+//    Licensed under the Apache License, Version 2.0 (the "License");
+//    you may not use this file except in compliance with the License.
+//    You may obtain a copy of the License at
+//
+//        http://www.apache.org/licenses/LICENSE-2.0
+//
+//    Unless required by applicable law or agreed to in writing, software
+//    distributed under the License is distributed on an "AS IS" BASIS,
+//    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//    See the License for the specific language governing permissions and
+//    limitations under the License.
+use super::*;
+
+use ndarray::{Array2, ShapeBuilder, ShapeError};
+
+impl<T: Clone> ReaderResultsRow<T> {
+    ///Converts our results into an Array2 with a row for each line of data and a column for each field.
+    ///Our results are already in row major order, so they become the C ordered storage of the array without being copied.
+    ///It only fails if results doesn't have num_lines * num_fields values in it.
+    pub fn into_array2(self) -> Result<Array2<T>, ShapeError> {
+        Array2::from_shape_vec((self.num_lines, self.num_fields), self.results)
+    }
+}
+
+impl<T: Clone> ReaderResultsCol<T> {
+    ///Converts our results into an Array2 with a row for each line of data and a column for each field.
+    ///Our results are already in column major order, so they become the Fortran ordered storage of the array without being copied.
+    ///It only fails if results doesn't have num_lines * num_fields values in it.
+    pub fn into_array2(self) -> Result<Array2<T>, ShapeError> {
+        Array2::from_shape_vec((self.num_lines, self.num_fields).f(), self.results)
+    }
+}
+
+///Our results always have a value for every field of every line, so they should always fit the shape of our array.
+///If they somehow don't, it's returned as an error rather than a panic.
+fn shape_error(err: ShapeError) -> ReaderError {
+    ReaderError::InvalidParams {
+        field: "results",
+        reason: format!("couldn't be turned into an array: {}", err),
+    }
+}
+
+//Our array is laid out in C order if ReaderParams::row_format is true and in Fortran order otherwise.
+//The C ordered array is parsed with $parse_c and the Fortran ordered one with $parse_f, which fills a single buffer
+//that then becomes the storage of the array.
+macro_rules! load_array {
+    ($parse_c:ident, $parse_f:ident, $src:expr, $params:expr) => {{
+        let array = if $params.row_format {
+            let typed_results: TypedReaderResultsRows<T> = $parse_c($src, $params)?;
+            ReaderResultsRow::from(typed_results).into_array2()
+        } else {
+            let typed_results: TypedReaderResultsColsPacked<T> = $parse_f($src, $params)?;
+            ReaderResultsCol::from(typed_results).into_array2()
+        };
+        array.map_err(shape_error)
+    }};
+}
+
+///load_txt_array reads in a data file that is made up of a primitive number type such as f64 straight into an Array2,
+///with a row for each line of data and a column for each field. The array is in C order if ReaderParams::row_format is true and
+///in Fortran order otherwise, which is the same layout that our results would have had. Either way the fields are saved off
+///straight into the storage of the array, so it's never copied. In Fortran order the lines of the file are counted first so that
+///each column can be given all of its room up front, and the file is parsed on a single thread even with the parallel feature.
+///The values are only moved if some lines weren't read in, such as lines that were skipped over for errors, or if the file
+///is compressed and couldn't be counted.
+///It can fail in a number of ways related to invalid parameters or the data file having malformed fields. These errors are
+///percolated up to whatever is calling this in the form of the ReaderError type. If a malformed field is seen the error does
+///contain information about what line number of the data file has the malformed field.
+///
+///Input -
+///
+/// f is simply the location of the file.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains an Array2 or an error.
+pub fn load_txt_array<T>(f: &str, params: &ReaderParams) -> Result<Array2<T>, ReaderError>
+where
+    T: Clone + Send,
+    LexicalParse: ParseField<T>,
+{
+    load_array!(parse_file, parse_txt_counted, f, params)
+}
+
+///load_reader_array is the same as load_txt_array except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location. A reader can only be read once, so in Fortran order the lines
+///can't be counted first. Instead the columns are given more room and moved over as the data is read in.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains an Array2 or an error.
+pub fn load_reader_array<T, R: BufRead>(reader: R, params: &ReaderParams) -> Result<Array2<T>, ReaderError>
+where
+    T: Clone,
+    LexicalParse: ParseField<T>,
+{
+    load_array!(parse_reader, parse_reader, reader, params)
+}
+
+///load_bytes_array is the same as load_txt_array except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains an Array2 or an error.
+pub fn load_bytes_array<T>(bytes: &[u8], params: &ReaderParams) -> Result<Array2<T>, ReaderError>
+where
+    T: Clone + Send,
+    LexicalParse: ParseField<T>,
+{
+    load_array!(parse_slice, parse_bytes_counted, bytes, params)
+}
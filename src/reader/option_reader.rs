@@ -0,0 +1,80 @@
+// This file is a part of the Rust Data Reader Library
+// Copyright 2018 Robert Carson
+//
+//    Licensed under the Apache License, Version 2.0 (the "License");
+//    you may not use this file except in compliance with the License.
+//    You may obtain a copy of the License at
+//
+//        http://www.apache.org/licenses/LICENSE-2.0
+//
+//    Unless required by applicable law or agreed to in writing, software
+//    distributed under the License is distributed on an "AS IS" BASIS,
+//    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//    See the License for the specific language governing permissions and
+//    limitations under the License.
+use super::*;
+
+use lexical::FromLexical;
+
+///load_txt_opt reads in a data file that is made up of columns of type T that might have missing values in them, such as f64 or i32.
+///Each field is read in as Option<T>, where missing fields that don't have a fill value are None and every other field is Some.
+///So, ReaderParams::missing_values should be used without a fill value to tell us what the missing fields look like.
+///The results then tell us exactly which fields were missing, since get_row and get_col return None for them.
+///It can also fail in a number of other ways related to invalid parameters or the data file having malformed fields. These errors are
+///percolated up to whatever is calling this in the form of the ReaderError type. If a malformed field is seen the error
+///does contain information about what line number of the data file has the malformed field.
+///Types that only support the FromStr trait can be read in the same way using load_text!(@option f, params, type).
+///
+///Input -
+///
+/// f is simply the location of the file.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_txt_opt<T>(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<Option<T>>>, ReaderError>
+where
+    T: FromLexical + Clone + 'static,
+{
+    load_text_lexical!(@option @parse parse_file, f, params, T)
+}
+
+///load_reader_opt is the same as load_txt_opt except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_reader_opt<T, R: BufRead>(reader: R, params: &ReaderParams) -> Result<Box<dyn ReaderResults<Option<T>>>, ReaderError>
+where
+    T: FromLexical + Clone + 'static,
+{
+    load_text_lexical!(@option @parse parse_reader, reader, params, T)
+}
+
+///load_bytes_opt is the same as load_txt_opt except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_bytes_opt<T>(bytes: &[u8], params: &ReaderParams) -> Result<Box<dyn ReaderResults<Option<T>>>, ReaderError>
+where
+    T: FromLexical + Clone + 'static,
+{
+    load_text_lexical!(@option @parse parse_slice, bytes, params, T)
+}
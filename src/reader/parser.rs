@@ -15,27 +15,34 @@
 use super::*;
 
 use std::fs::File;
-use std::io::{BufRead, Seek};
-use std::io::{SeekFrom};
+use std::io::{BufRead};
 #[cfg(not(feature = "mmap"))]
 use std::io::{BufReader};
-#[cfg(feature = "mmap")]
-use std::io::Cursor;
+#[cfg(all(feature = "ndarray", not(feature = "mmap")))]
+use std::io::{Seek, SeekFrom};
 #[cfg(feature = "mmap")]
 use memmap::MmapOptions;
 use std::str;
 use std::vec::*;
 
-use anyhow::Error;
 use memchr::memchr2_iter;
 use super::parser_core::{NwLine, ParserState, CoreData};
+use super::missing::MissingFields;
+use super::parser_utility::{read_names, FooterReader};
+#[cfg(feature = "ndarray")]
+use super::parser_utility::count_file_lines;
+#[cfg(any(feature = "gzip", feature = "zstd", feature = "bzip2", feature = "xz"))]
+use super::compression::Compression;
 
 
 ///parse_txt reads in a data file that is made up any type(s). It parses the data file finding all of the field data and saving off in its raw
 ///byte form. It can fail in a number of other ways related to invalid parameters or the data file having malformed fields. These errors are
-///percolated up to whatever is calling this in the form of the Error type.
+///percolated up to whatever is calling this in the form of the ReaderError type.
 ///One should therefore check to make sure no errors are obtained when examining the file. If a malformed field is seen the error
-///does contain information about what line number of the data file has the malformed field.
+///does contain information about what line number and byte offset of the data file has the malformed field.
+///
+///If any of the gzip, zstd, bzip2, or xz features are turned on, a compressed file is detected by its magic bytes
+///or file extension and is decompressed as it's being parsed.
 ///
 ///Input -
 ///
@@ -46,189 +53,389 @@ use super::parser_core::{NwLine, ParserState, CoreData};
 ///Output -
 ///
 /// A Result type that either contains a RawReaderResults structure or an error.
-pub fn parse_txt<T>(f: &str, params: &ReaderParams) -> Result<T, Error> 
+pub fn parse_txt<T>(f: &str, params: &ReaderParams) -> Result<T, ReaderError> 
 where
     T: RawReaderParse,
 {
 
     let file = File::open(f)?;
 
+    //With a memory mapped file we already have all of our data as one large slice
+    #[cfg(feature = "mmap")]
+    {
+        let buffer = unsafe { MmapOptions::new().map(&file)? };
+        //A compressed file has to be stream decompressed before it can be parsed
+        #[cfg(any(feature = "gzip", feature = "zstd", feature = "bzip2", feature = "xz"))]
+        if let Some(compression) = Compression::detect(f, &buffer[..]) {
+            return parse_reader(compression.decoder(&buffer[..])?, params);
+        }
+        parse_bytes(&buffer[..], params)
+    }
+
+    //We're explicitly using the raw bytes here
+    #[cfg(not(feature = "mmap"))]
+    {
+        #[allow(unused_mut)]
+        let mut reader = BufReader::with_capacity(BUF_SIZE, file);
+        //A compressed file has to be stream decompressed before it can be parsed
+        #[cfg(any(feature = "gzip", feature = "zstd", feature = "bzip2", feature = "xz"))]
+        if let Some(compression) = Compression::detect(f, reader.fill_buf()?) {
+            return parse_reader(compression.decoder(reader)?, params);
+        }
+        parse_reader(reader, params)
+    }
+}
+
+///parse_reader is the same as parse_txt except that instead of a file location it reads from any type that supports
+///the BufRead trait. This allows data that lives in memory (std::io::Cursor), an already opened file, a decompression stream,
+///stdin, or any other stream to be parsed. The data is read from the reader's current position, and it is only ever read through once.
+///So, the reader does not need to be seekable. Since we don't know how much data there is up front our results grow as the data is read in.
+///If skip_footer is used the last lines of data are held back in a ring buffer, so that they never make it to the parser.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a RawReaderResults structure or an error.
+pub fn parse_reader<T, R>(reader: R, params: &ReaderParams) -> Result<T, ReaderError> 
+where
+    T: RawReaderParse,
+    R: BufRead,
+{
+    parse_reader_sized(reader, params, 0)
+}
+
+///The same as parse_reader except that our results are created with room for num_lines lines of data.
+fn parse_reader_sized<T, R>(mut reader: R, params: &ReaderParams, num_lines: usize) -> Result<T, ReaderError> 
+where
+    T: RawReaderParse,
+    R: BufRead,
+{
     //our comment string
     //If we don't have one then we just say a comment is a newline character.
-    //The newline check comes first in all of these so it'll be as if the parser never
-    //has to worry about the comments.
-    let cmt = if let Some(x) = params.comments {
-        x
+    let cmt = params.comments.unwrap_or(b'\n');
+
+    //File line number used for Error information
+    let mut fln = 0;
+
+    //If we skip any header lines then we need to skip forward through the file by
+    //the correct number of lines when not taking into account commented lines.
+    let (sk_h, pos) = skip_header_lines(&mut reader, &mut fln, cmt, params.skip_header.unwrap_or_default())?;
+
+    //Our column names come right after the header, and we need them before we can find our columns.
+    let (names, nm_h, nm_pos) = read_header_names(&mut reader, &mut fln, params)?;
+    let cols = get_cols(params, &names)?;
+    let missing = get_missing(params, &names)?;
+
+    //We are initializing our ReaderResult structure
+    //If we aren't using usecols we won't know the number of fields until we've read the first line of data.
+    let mut results:T = T::new(cols.len(), num_lines);
+    results.set_params(params);
+    results.set_names(get_names(names, &cols, params.row_format));
+
+    let mut core_data = CoreData::new(params, &cols, missing.as_ref(), &mut results, 0, fln, pos + nm_pos);
+
+    //The most lines we'll ever need to read in
+    let num_lines_read = params.max_rows.unwrap_or(usize::MAX);
+
+    //If we have a footer to skip then our reader needs to hold those lines back from us.
+    let sk_f = params.skip_footer.unwrap_or_default();
+    let num_held_lines = if sk_f > 0 {
+        let mut footer_reader = FooterReader::new(reader, cmt, sk_f);
+        parse_lines(&mut footer_reader, &mut core_data, num_lines_read)?;
+        footer_reader.num_held_lines()
     } else {
-        b'\n'
+        parse_lines(&mut reader, &mut core_data, num_lines_read)?;
+        0
     };
 
+    //Now that we've gone through our data we know how many readable lines it had, so
+    //we can make sure that the parameters we provided were valid for it.
+    check_num_lines(sk_h + nm_h + core_data.results.get_num_lines() + core_data.num_rejected + num_held_lines, params)?;
+
+    //Assumming everything went well we save off our results.
+    Ok(results)
+}
+
+///parse_bytes is the same as parse_txt except that it parses data that already lives in memory such as the contents of a
+///string (use as_bytes() on it), an HTTP body, or a memory mapped file. Our slice is treated as a single buffer,
+///so the state machine runs directly over it without any intermediate buffering or copying of the data before the fields
+///are saved off. The only exception is when skip_footer is used since those lines need to be held back from the parser.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a RawReaderResults structure or an error.
+pub fn parse_bytes<T>(bytes: &[u8], params: &ReaderParams) -> Result<T, ReaderError> 
+where
+    T: RawReaderParse,
+{
+    parse_reader(bytes, params)
+}
+
+///The most lines of data that our data can have, which is every line that isn't blank or commented except for our header,
+///names, and footer lines, and no more than max_rows. It's only more than the number of lines that are read in if some of them
+///are skipped over for having errors or a quoted field has newlines within it.
+#[cfg(feature = "ndarray")]
+fn max_data_lines<R: BufRead>(reader: &mut R, params: &ReaderParams) -> Result<usize, ReaderError> {
+    let num_lines = count_file_lines(reader, params.comments.unwrap_or(b'\n'))?;
+    let num_names = if params.names == Some(Names::Header) { 1 } else { 0 };
+    let num_lines = num_lines.saturating_sub(params.skip_header.unwrap_or_default() + params.skip_footer.unwrap_or_default() + num_names);
+    Ok(params.max_rows.map_or(num_lines, |x| x.min(num_lines)))
+}
+
+///parse_txt_counted is the same as parse_txt except that the lines of the file are counted before it's parsed, so our results
+///are created with room for all of their lines up front. This is for results that can't grow without moving what they've
+///already saved off. A compressed file would need to be decompressed twice to be counted, so it's parsed like it is in parse_txt.
+#[cfg(feature = "ndarray")]
+pub(crate) fn parse_txt_counted<T>(f: &str, params: &ReaderParams) -> Result<T, ReaderError>
+where
+    T: RawReaderParse,
+{
+    let file = File::open(f)?;
+
     #[cfg(feature = "mmap")]
-    let buffer = unsafe { MmapOptions::new().map(&file)? };
+    {
+        let buffer = unsafe { MmapOptions::new().map(&file)? };
+        #[cfg(any(feature = "gzip", feature = "zstd", feature = "bzip2", feature = "xz"))]
+        if let Some(compression) = Compression::detect(f, &buffer[..]) {
+            return parse_reader(compression.decoder(&buffer[..])?, params);
+        }
+        parse_bytes_counted(&buffer[..], params)
+    }
 
-    //We're explicitly using the raw bytes here
     #[cfg(not(feature = "mmap"))]
-    let mut reader = BufReader::with_capacity(BUF_SIZE, file);
-    #[cfg(feature = "mmap")]
-    let mut reader = Cursor::new(&buffer[..]);
+    {
+        let mut reader = BufReader::with_capacity(BUF_SIZE, file);
+        #[cfg(any(feature = "gzip", feature = "zstd", feature = "bzip2", feature = "xz"))]
+        if let Some(compression) = Compression::detect(f, reader.fill_buf()?) {
+            return parse_reader(compression.decoder(reader)?, params);
+        }
+        let num_lines = max_data_lines(&mut reader, params)?;
+        reader.seek(SeekFrom::Start(0))?;
+        parse_reader_sized(reader, params, num_lines)
+    }
+}
 
-    //We are finding how many lines in our data file are actually readable and are not commented lines.
-    let num_lines = read_num_file_lines(& mut reader, cmt);
-    //We need to rewind our file back to the start.
-    reader.seek(SeekFrom::Start(0))?;
+///parse_bytes_counted is the same as parse_txt_counted except that it parses data that already lives in memory.
+#[cfg(feature = "ndarray")]
+pub(crate) fn parse_bytes_counted<T>(bytes: &[u8], params: &ReaderParams) -> Result<T, ReaderError>
+where
+    T: RawReaderParse,
+{
+    let num_lines = max_data_lines(&mut &bytes[..], params)?;
+    parse_reader_sized(bytes, params, num_lines)
+}
 
-    //The next portion of lines is some minor error handling to make sure our parameters we provided were valid for our data file.
-    //We're checking to see if we have a valid number of skipped lines for the header.
-    match &params.skip_header {
-        Some(x) => {
-            if *x >= num_lines {
-                return Err(format_err!(
-                    "Input for skip_header greater than the number of readable lines in the file"
-                ));
-            }
+///Runs through all of the data in our reader until either we've run out of data or we've read in num_lines_read lines.
+fn parse_lines<T, R>(reader: &mut R, core_data: &mut CoreData<T>, num_lines_read: usize) -> Result<(), ReaderError>
+where
+    T: RawReaderParse,
+    R: BufRead,
+{
+    let mut state = ParserState::NwLine(NwLine{});
+
+    loop {
+        //We first find the length of our buffer
+        let length = {
+            //We fill the buffer up. Our buffer is mutable which is why it's in this block
+            let buffer = reader.fill_buf()?;
+            state = parse_buffer(buffer, state, core_data, num_lines_read)?;
+            buffer.len()
+        };
+        //We now need to consume everything in our buffer, so it's marked off as no longer being needed
+        reader.consume(length);
+        //If our buffer came back empty we've reached the end of our file and can now exit.
+        if (length == 0) | (core_data.results.get_num_lines() == num_lines_read) {
+            break;
         }
-        None => (),
     }
 
-    //Now that we know our number is valid we are setting a variable for our skipped header lines to be equal to our skippable lines.
-    let sk_h = if let Some(x) = params.skip_header {
-        x
-    } else {
-        0
-    };
+    parse_eof(state, core_data, num_lines_read)
+}
+
+///Checks that our data had readable lines in it, and that the skip_header and skip_footer parameters were valid for the number of them.
+pub(crate) fn check_num_lines(num_lines: usize, params: &ReaderParams) -> Result<(), ReaderError> {
+    //Data that's empty or only has blank and commented lines doesn't have anything for us to read in.
+    if num_lines == 0 {
+        return Err(ReaderError::NoData);
+    }
+
+    //We're checking to see if we have a valid number of skipped lines for the header.
+    if let Some(x) = params.skip_header {
+        if x >= num_lines {
+            return Err(ReaderError::InvalidParams {
+                field: "skip_header",
+                reason: format!("greater than or equal to the number of readable lines in the file {}", num_lines),
+            });
+        }
+    }
 
     //We're checking to see if we have a valid number of skipped lines for the footer.
-    match &params.skip_footer {
-        Some(x) => {
-            if *x >= num_lines {
-                return Err(format_err!(
-                    "Input for skip_footer greater than the number of readable lines in the file"
-                ));
-            }
+    if let Some(x) = params.skip_footer {
+        if x >= num_lines {
+            return Err(ReaderError::InvalidParams {
+                field: "skip_footer",
+                reason: format!("greater than or equal to the number of readable lines in the file {}", num_lines),
+            });
         }
-        None => (),
     }
 
-    //Now that we know our number is valid we are setting a variable for our skipped footer lines to be equal to our skippable lines.
-    let sk_f = if let Some(x) = params.skip_footer {
-        x
-    } else {
-        0
-    };
     //We need to error if the number of lines we can read is equal to or less than the number of skipped header and footer lines.
+    //Each of them is less than our number of lines at this point, so this can only happen when both of them are used.
+    let sk_h = params.skip_header.unwrap_or_default();
+    let sk_f = params.skip_footer.unwrap_or_default();
     if num_lines <= (sk_h + sk_f) {
-        return Err(format_err!("Input for skip_footer and skip_header greater than or equal to the number of readable lines in the file"));
-    }
-    //Here we're determining the number of lines that we need to read from our file.
-    let num_lines_read = match &params.max_rows {
-        //If we've set a maximum number of rows. We need to first see if that number is greater than
-        //the number of readable none skipped lines.
-        //If we didn't then the maximum number is set to our number of readable non skipped lines.
-        Some(x) => {
-            let diff_lines = num_lines - sk_h - sk_f;
-            if diff_lines > *x {
-                *x
-            } else {
-                diff_lines
-            }
-        }
-        None => num_lines - sk_h - sk_f,
-    };
+        return Err(ReaderError::InvalidParams {
+            field: "skip_footer",
+            reason: format!("and skip_header greater than or equal to the number of readable lines in the file {}", num_lines),
+        });
+    }
+    Ok(())
+}
 
+///Returns our delimiter value along with whether or not we're delimiting using whitespace.
+pub(crate) fn get_delim(params: &ReaderParams) -> (u8, bool) {
     //We're simply stating whether we're using whitespaces or not for our delimiter.
-    let delim_ws = match &params.delimiter {
-        Delimiter::WhiteSpace => true,
-        Delimiter::Any(_b) => false,
-    };
     //Our delimeter value. If we are delimiting using whitespace we set this as a space. However, we'll take into consideration tabs as well.
-    let delim = match &params.delimiter {
-        Delimiter::WhiteSpace => b' ',
-        Delimiter::Any(b) => *b,
-    };
-
-    //File line number used for Error information
-    let mut fln = 0;
-
-    //If we skip any header lines then we need to skip forward through the file by
-    //the correct number of lines when not taking into account commented lines.
-    skip_header_lines(&mut reader, &mut fln, cmt, sk_h);
+    match &params.delimiter {
+        Delimiter::WhiteSpace => (b' ', true),
+        Delimiter::Any(b) => (*b, false),
+    }
+}
 
-    let current_pos = reader.stream_position()?;
-    //We'll need to now the total number of fields later on and set this variable initially outside the main loop.
-    let tot_fields = count_num_fields(&mut reader, cmt, delim, delim_ws);
-    //We need to rewind our file back to the start.
-    reader.seek(SeekFrom::Start(current_pos))?;
+///Reads in the names of our columns if we want them. It returns the names along with the number of lines of data
+///and the number of bytes that were read in to find them.
+pub(crate) fn read_header_names<R>(reader: &mut R, fln: &mut usize, params: &ReaderParams) -> Result<(Vec<String>, usize, usize), ReaderError>
+where
+    R: BufRead,
+{
+    match params.names {
+        Some(names) => {
+            let cmt = params.comments.unwrap_or(b'\n');
+            let (delim, delim_ws) = get_delim(params);
+            read_names(reader, fln, cmt, delim, delim_ws, params.quote, names)
+        }
+        None => Ok((Vec::<String>::new(), 0, 0)),
+    }
+}
 
-    //Next we need to get a list of our columns we might be using. If we aren't we supply an empty vector, so we can easily check if the len is 0.
-    //While these values are 0 indexed externally, internally it's a bit easier to deal with 1-based indexing for the time being.
+///Next we need to get a list of our columns we might be using. If we aren't we supply an empty vector, so we can easily check if the len is 0.
+///While these values are 0 indexed externally, internally it's a bit easier to deal with 1-based indexing for the time being.
+///Columns given by name are looked up in our column names, and if a name can't be found an error is returned.
+///We can't check whether the column numbers are valid until we know how many fields are in the first line of our data.
+pub(crate) fn get_cols(params: &ReaderParams, names: &[String]) -> Result<Vec<usize>, ReaderError> {
     let cols = match &params.usecols {
-        Some(x) => {
-            if x.len() > tot_fields {
-                return Err(format_err!("Input for usecols contains more values than the total number of fields {}", tot_fields));
-            }
-            if x.iter().any(|&x| x > tot_fields) {
-                return Err(format_err!("Input for usecols contains a value greater than or equal to the number of fields {}", tot_fields));
-            }
-            x.iter().map(|&x| x + 1).collect::<Vec<usize>>()
-        }
+        Some(x) => x.iter().map(|col| get_col(col, names, "usecols")).collect::<Result<Vec<usize>, ReaderError>>()?,
         None => Vec::<usize>::new(),
     };
+    //A field can only be saved off to one place, so any column after the first one that's the same would never be filled in.
+    if (1..cols.len()).any(|i| cols[..i].contains(&cols[i])) {
+        return Err(ReaderError::InvalidParams {
+            field: "usecols",
+            reason: String::from("contains the same column more than once"),
+        });
+    }
+    Ok(cols)
+}
 
-    let nfields = if !cols.is_empty() { cols.len() } else { tot_fields };
-    //We are initializing our ReaderResult structure
-    let mut results:T = T::new(nfields, num_lines_read);
-
-    let mut core_data = CoreData::<T>{
-        length: 0,
-        offset: 0,
-        cmt,
-        delim_ws,
-        delim,
-        fln,
-        cols: & cols,
-        field_counter: 0,
-        current_field: 0,
-        tot_fields,
-        results: &mut results,
-    };
+///Sets up our missing values if we have any, which might need our column names to find their columns.
+pub(crate) fn get_missing(params: &ReaderParams, names: &[String]) -> Result<Option<MissingFields>, ReaderError> {
+    params.missing_values.as_ref().map(|x| MissingFields::new(x, names)).transpose()
+}
 
-    //The loop here is where all of the magic happens. It's designed so that it operates based on a state. So, we're running a 
-    //state machine to process everything. The optimizer is able to do a fairly decent job in turning this into performant code.
+///Returns the 1-based field number of a column. If the column is given by a name that isn't one of our column names,
+///an error is returned for the ReaderParams field that the column came from.
+pub(crate) fn get_col(col: &Column, names: &[String], field: &'static str) -> Result<usize, ReaderError> {
+    match col {
+        Column::Index(x) => Ok(x + 1),
+        Column::Name(name) => match names.iter().position(|x| x == name) {
+            Some(x) => Ok(x + 1),
+            None => Err(ReaderError::InvalidParams {
+                field,
+                reason: format!("contains the name {} which isn't one of the column names", name),
+            }),
+        },
+    }
+}
 
-    let mut state = ParserState::NwLine(NwLine{});
+///The names that get saved off in our results are only the ones for the columns that we're using.
+///Our columns are laid out in the order that usecols gives them in column major order, but in row major order
+///they're saved off in the order they appear in the data. So, our names need to be in the same order.
+pub(crate) fn get_names(names: Vec<String>, cols: &[usize], row_format: bool) -> Vec<String> {
+    if cols.is_empty() {
+        return names;
+    }
+    let mut cols = cols.to_vec();
+    if row_format {
+        cols.sort_unstable();
+    }
+    cols.iter().filter_map(|&x| names.get(x - 1).cloned()).collect()
+}
 
-    loop {
-        //We first find the length of our buffer
-        let length = {
-            //We fill the buffer up. Our buffer is mutable which is why it's in this block
-            let buffer = reader.fill_buf().unwrap();
-            //We're now going to use an explicit loop.
-            //I know this isn't idiomatic rust, but I couldn't really see a good way of skipping my iterator
-            //to a location of my choosing.
-            core_data.offset = 0;
-            //We're using the memchr crate to locate all of the most common newline characters
-            //It provides a nice iterator over our buffer that we can now use.
-            let mut newline = memchr2_iter(b'\n', b'\r', buffer);
-
-            //We don't want our loop index to go past our buffer length or else bad things could occur
-            core_data.length = buffer.len();
-            //Keeping it old school with some nice wild loops
-            while core_data.offset < core_data.length {
-                state = state.next(buffer, &mut newline, &mut core_data)?;
-                //Check to see if we've read enough lines in if so break out of the loop
-                if core_data.results.get_num_lines() == num_lines_read {
-                    break;
-                }
-            }
-            core_data.length
-        };
-        //We now need to consume everything in our buffer, so it's marked off as no longer being needed
-        reader.consume(length);
-        //If our length is less than our fixed buffer size we've reached the end of our file and can now exit.
-        if (length < BUF_SIZE) | (core_data.results.get_num_lines() == num_lines_read) {
+///The loop here is where all of the magic happens. It's designed so that it operates based on a state. So, we're running a 
+///state machine to process everything in our buffer. The optimizer is able to do a fairly decent job in turning this into performant code.
+///The state that we end on is returned, so that the next buffer can pick up where this one left off.
+#[inline(always)]
+pub(crate) fn parse_buffer<T>(buffer: &[u8], mut state: ParserState, core_data: &mut CoreData<T>, num_lines_read: usize) -> Result<ParserState, ReaderError>
+where
+    T: RawReaderParse,
+{
+    //We're now going to use an explicit loop.
+    //I know this isn't idiomatic rust, but I couldn't really see a good way of skipping my iterator
+    //to a location of my choosing.
+    core_data.offset = 0;
+    //We're using the memchr crate to locate all of the most common newline characters
+    //It provides a nice iterator over our buffer that we can now use.
+    let mut newline = memchr2_iter(b'\n', b'\r', buffer);
+
+    //We don't want our loop index to go past our buffer length or else bad things could occur
+    core_data.length = buffer.len();
+    //Keeping it old school with some nice wild loops
+    while core_data.offset < core_data.length {
+        //Check to see if we've read enough lines in if so break out of the loop
+        if core_data.results.get_num_lines() == num_lines_read {
             break;
         }
+        state = match state.next(buffer, &mut newline, core_data) {
+            Ok(state) => state,
+            //Depending on our parameters we might be able to skip over the line with the error
+            Err(err) => core_data.reject_line(err, buffer, &mut newline)?,
+        };
     }
-    //Assumming everything went well we save off our results.
-    Ok(results)
+    core_data.save_line_start(buffer, &state);
+    if let Some(&last_byte) = buffer.last() {
+        core_data.last_byte = last_byte;
+    }
+    core_data.pos += buffer.len();
+    Ok(state)
+}
+
+///If the last line of our data doesn't end with a newline character then it still needs to be closed out.
+///The easiest way to do that is to just feed the state machine a newline. A quoted field that was never closed has
+///taken in the rest of our data, so there's no line left to close out and we return an error instead.
+pub(crate) fn parse_eof<T>(state: ParserState, core_data: &mut CoreData<T>, num_lines_read: usize) -> Result<(), ReaderError>
+where
+    T: RawReaderParse,
+{
+    if let ParserState::QtField(_) = state {
+        return Err(ReaderError::UnterminatedQuote {
+            line: core_data.field_line,
+            offset: core_data.field_start,
+        });
+    }
+    if core_data.results.get_num_lines() < num_lines_read {
+        let eol = [b'\n'];
+        parse_buffer(&eol, state, core_data, num_lines_read)?;
+    }
+    Ok(())
 }
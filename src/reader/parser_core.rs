@@ -16,10 +16,18 @@
 use super::*;
 
 use memchr::Memchr2;
-
-pub(crate) trait ReadCore: std::io::Read + std::io::BufRead + std::io::Seek {}
-impl<T: std::io::Read + std::io::BufRead + std::io::Seek> ReadCore for T {}
-
+use super::parser::get_delim;
+use super::missing::MissingFields;
+
+///All of the data that our parser states share with one another.
+///pos is the byte offset of the start of our current buffer within all of the data,
+///while line_start and field_start are the byte offsets of the start of the current line and field,
+///and field_line is the file line number that the current field started on.
+///They're only used to tell where an error was found.
+///If we're skipping over lines with errors, line_buf holds the start of the current line if it began in an earlier buffer,
+///rejected holds a skipped line that runs on into the next buffer, and num_rejected is the number of lines skipped so far.
+///If we have missing values then missing tells us what they are, and empty_fields is whether or not fields with nothing in them
+///between delimiters count as a field.
 pub(crate) struct CoreData<'a, RRP>
 where
     RRP: RawReaderParse,
@@ -29,22 +37,307 @@ where
     pub cmt: u8,
     pub delim_ws: bool,
     pub delim: u8,
+    pub quote: u8,
     pub fln: usize,
+    pub pos: usize,
+    pub last_byte: u8,
+    pub line_start: usize,
+    pub field_start: usize,
+    pub field_line: usize,
     pub cols: &'a Vec::<usize>,
     pub field_counter: usize,
     pub current_field: usize,
     pub tot_fields: usize,
+    pub row_errors: RowErrors,
+    pub line_buf: Vec<u8>,
+    pub rejected: Option<RejectedLine>,
+    pub num_rejected: usize,
+    pub missing: Option<&'a MissingFields>,
+    pub empty_fields: bool,
     pub results: &'a mut RRP,
 }
 
+impl<'a, RRP> CoreData<'a, RRP>
+where
+    RRP: RawReaderParse,
+{
+    ///Sets up our parser to start at file line number fln and byte offset pos within our data.
+    ///If we don't know the total number of fields yet tot_fields should be 0, and it'll be found from our first line of data.
+    pub(crate) fn new(params: &ReaderParams, cols: &'a Vec<usize>, missing: Option<&'a MissingFields>, results: &'a mut RRP, tot_fields: usize, fln: usize, pos: usize) -> CoreData<'a, RRP> {
+        //our comment string
+        //If we don't have one then we just say a comment is a newline character.
+        //The newline check comes first in all of these so it'll be as if the parser never
+        //has to worry about the comments.
+        let cmt = params.comments.unwrap_or(b'\n');
+        let (delim, delim_ws) = get_delim(params);
+        //The same goes for our quote character, since a field can never start with a newline character.
+        let quote = params.quote.unwrap_or(b'\n');
+
+        CoreData {
+            length: 0,
+            offset: 0,
+            cmt,
+            delim_ws,
+            delim,
+            quote,
+            fln,
+            pos,
+            last_byte: 0,
+            line_start: pos,
+            field_start: pos,
+            field_line: fln,
+            cols,
+            field_counter: 0,
+            current_field: 0,
+            tot_fields,
+            row_errors: params.row_errors,
+            line_buf: Vec::<u8>::new(),
+            rejected: None,
+            num_rejected: 0,
+            missing,
+            empty_fields: missing.is_some() & !delim_ws,
+            results,
+        }
+    }
+
+    ///Moves our offset past the end of the current line and increments our file line number.
+    ///If the end of the line isn't within our current buffer then the rest of the line lives in the next buffer,
+    ///so we return the Cmt state which will skip over it once the next buffer is read in.
+    #[inline(always)]
+    pub(crate) fn skip_line(&mut self, newline: &mut Memchr2) -> ParserState {
+        self.fln += 1;
+        match newline.next() {
+            Some(val) => {
+                self.offset = val + 1;
+                ParserState::NwLine(NwLine{})
+            }
+            None => {
+                self.offset = self.length;
+                ParserState::Cmt(Cmt{})
+            }
+        }
+    }
+
+    ///Whether or not the character right before our offset is a '\r', which might have been the last character of our previous buffer.
+    ///A '\n' right after a '\r' is a part of the same line ending, so it doesn't count as a line of its own.
+    #[inline(always)]
+    pub(crate) fn after_cr(&self, buffer: &[u8]) -> bool {
+        let prev = if self.offset > 0 { buffer[self.offset - 1] } else { self.last_byte };
+        prev == b'\r'
+    }
+
+    ///We've come across the first character of a new field. If we're using all of the columns we save it off.
+    ///Otherwise, we need to check whether or not it's one of the columns that we want to keep.
+    ///If the field starts with our quote character then it's a quoted field, and the quote itself isn't saved off.
+    #[inline(always)]
+    pub(crate) fn start_field(&mut self, buf_val: u8) -> ParserState {
+        self.field_start = self.pos + self.offset - 1;
+        self.field_line = self.fln + 1;
+        let keep = self.select_field();
+        if buf_val == self.quote {
+            ParserState::QtField(QtField{ keep })
+        }
+        else if keep {
+            self.results.set_results(buf_val, self.current_field);
+            ParserState::Field(Field{})
+        }
+        else {
+            ParserState::SkField(SkField{})
+        }
+    }
+
+    ///Returns whether or not the field we're starting is one that we're keeping, and if so sets current_field to where it's saved off.
+    ///If we don't know how many fields are in our data yet, then we're still on the first line and our results
+    ///need to be told about the new field.
+    #[inline(always)]
+    fn select_field(&mut self) -> bool {
+        match &self.cols.len() {
+            0 => {
+                if self.tot_fields == 0 {
+                    self.results.set_num_fields(self.field_counter);
+                }
+                //A line with more fields than our first line can't be saved off, and it'll error once we reach the end of it.
+                else if self.field_counter > self.tot_fields {
+                    return false;
+                }
+                self.current_field = self.field_counter;
+                true
+            }
+            _ => {
+                let pos = self.cols.iter().position(|&x| x == self.field_counter);
+                match pos {
+                    Some(x) => {
+                        self.current_field = x + 1;
+                        true
+                    }
+                    None => false,
+                }
+            }
+        }
+    }
+
+    ///We've reached the end of a field that we're keeping, so our results can now save it off.
+    ///If our results fail to convert the field, the error is filled in with where the field lives in our data.
+    ///This needs to be called before we've moved on to the next field or line.
+    #[inline(always)]
+    pub(crate) fn end_field(&mut self) -> Result<(), ReaderError> {
+        if let Some(missing) = self.missing {
+            if let Some(fill) = missing.get_fill(self.field_counter, self.results.get_field(self.current_field)) {
+                self.results.fill_field(self.current_field, fill);
+            }
+        }
+        if let Err(mut err) = self.results.set_index(self.current_field) {
+            err.set_field_location(self.results.get_num_lines(), self.field_counter - 1, self.fln + 1, self.field_start);
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    ///We've come across a field with nothing in it right before a delimiter or the end of a line. If empty fields count as fields,
+    ///then it's missing and gets filled in if it's one that we're keeping.
+    #[inline(always)]
+    pub(crate) fn empty_field(&mut self) -> Result<(), ReaderError> {
+        self.field_start = self.pos + self.offset;
+        if self.select_field() {
+            self.end_field()?;
+        }
+        Ok(())
+    }
+
+    ///The first line of data tells us how many fields every other line should have, so we save it off here.
+    ///Now that we know the number of fields we can also make sure our usecols were valid.
+    #[inline(always)]
+    pub(crate) fn set_tot_fields(&mut self) -> Result<(), ReaderError> {
+        if (self.tot_fields == 0) & (self.field_counter > 0) {
+            self.tot_fields = self.field_counter;
+            check_cols(self.cols, self.tot_fields)?;
+        }
+        Ok(())
+    }
+
+    ///We've reached the end of a line of data, so we make sure it had the same number of fields as our first line.
+    ///This needs to be called before we've moved on to the next line.
+    #[inline(always)]
+    pub(crate) fn end_line(&mut self) -> Result<(), ReaderError> {
+        self.set_tot_fields()?;
+        if self.field_counter != self.tot_fields {
+            return Err(field_count_error(self));
+        }
+        self.results.incr_num_lines();
+        self.field_counter = 0;
+        Ok(())
+    }
+
+    ///The current line of data had an error. If we're failing on errors, or the error isn't about just this line,
+    ///then we return it. Otherwise, anything that was saved off from this line is removed from our results and we skip
+    ///over the rest of the line. If the end of the line isn't within our current buffer then we return the Reject state,
+    ///which will skip over the rest of it once the next buffer is read in.
+    #[cold]
+    pub(crate) fn reject_line(&mut self, err: ReaderError, buffer: &[u8], newline: &mut Memchr2) -> Result<ParserState, ReaderError> {
+        if (self.row_errors == RowErrors::Fail) | !err.is_row_error() {
+            return Err(err);
+        }
+
+        let num_fields = if self.cols.is_empty() { self.tot_fields } else { self.cols.len() };
+        self.results.rollback_line(num_fields);
+        self.field_counter = 0;
+        self.num_rejected += 1;
+        self.fln += 1;
+
+        let mut rejected = RejectedLine {
+            line: self.fln,
+            offset: self.line_start,
+            bytes: std::mem::take(&mut self.line_buf),
+            reason: err,
+        };
+        //Our line might have started in an earlier buffer, in which case the start of it is already in line_buf.
+        let start = self.line_start.saturating_sub(self.pos);
+        match newline.next() {
+            Some(val) => {
+                rejected.bytes.extend_from_slice(&buffer[start..val]);
+                self.offset = val + 1;
+                self.add_rejected(rejected);
+                Ok(ParserState::NwLine(NwLine{}))
+            }
+            None => {
+                rejected.bytes.extend_from_slice(&buffer[start..]);
+                self.offset = self.length;
+                self.rejected = Some(rejected);
+                Ok(ParserState::Reject(Reject{}))
+            }
+        }
+    }
+
+    ///Saves off a line that we've skipped over if we're reporting them.
+    #[cold]
+    fn add_rejected(&mut self, rejected: RejectedLine) {
+        if self.row_errors == RowErrors::SkipAndReport {
+            self.results.add_rejected(rejected);
+        }
+    }
+
+    ///If we're skipping over lines with errors and our buffer ended part way through a line, then the start of that line
+    ///needs to be saved off since we won't have access to it anymore once the next buffer is read in.
+    #[inline(always)]
+    pub(crate) fn save_line_start(&mut self, buffer: &[u8], state: &ParserState) {
+        if self.row_errors != RowErrors::Fail {
+            match state {
+                ParserState::NwLine(_) | ParserState::Reject(_) => {}
+                _ => {
+                    let start = self.line_start.saturating_sub(self.pos);
+                    self.line_buf.extend_from_slice(&buffer[start.min(buffer.len())..]);
+                }
+            }
+        }
+    }
+}
+
+///Makes sure that the 1-based columns we want to use are valid for the total number of fields in our data.
+pub(crate) fn check_cols(cols: &[usize], tot_fields: usize) -> Result<(), ReaderError> {
+    if cols.len() > tot_fields {
+        return Err(ReaderError::InvalidParams {
+            field: "usecols",
+            reason: format!("contains more values than the total number of fields {}", tot_fields),
+        });
+    }
+    if cols.iter().any(|&x| x > tot_fields) {
+        return Err(ReaderError::InvalidParams {
+            field: "usecols",
+            reason: format!("contains a value greater than or equal to the number of fields {}", tot_fields),
+        });
+    }
+    Ok(())
+}
+
+///Returns the error for a line of data that had a different number of fields than our first line of data.
+///Our file line number hasn't been moved on to the line that we're erroring on yet at this point.
+#[cold]
+fn field_count_error<RRP: RawReaderParse>(core_data: &CoreData<RRP>) -> ReaderError {
+    ReaderError::FieldCountMismatch {
+        line: core_data.fln + 1,
+        offset: core_data.line_start,
+        expected: core_data.tot_fields,
+        found: core_data.field_counter,
+    }
+}
+
+///Returns the error for a line of data that ended with a delimiter.
+#[cold]
+fn trailing_delim_error<RRP: RawReaderParse>(core_data: &CoreData<RRP>) -> ReaderError {
+    ReaderError::TrailingDelimiter {
+        line: core_data.fln + 1,
+        offset: core_data.line_start,
+    }
+}
+
 pub(crate) trait Parser
 {
-    fn next<RRP: RawReaderParse>(&self, buffer: &[u8], newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error>;
-    fn parse_delim<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error>;
-    fn parse_whitespace<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error>;
-    fn parse_newline<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error>;
-    fn parse_comment<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error>;
-    fn parse_others<RRP: RawReaderParse>(&self, buf_val: u8, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error>;
+    fn next<RRP: RawReaderParse>(&self, buffer: &[u8], newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError>;
+    fn parse_delim<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError>;
+    fn parse_whitespace<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError>;
+    fn parse_newline<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError>;
+    fn parse_comment<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError>;
+    fn parse_others<RRP: RawReaderParse>(&self, buf_val: u8, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError>;
 }
 
 pub(crate) struct NwLine {}
@@ -52,6 +345,10 @@ pub(crate) struct Delim {}
 pub(crate) struct Space {}
 pub(crate) struct Field {}
 pub(crate) struct SkField {}
+pub(crate) struct Cmt {}
+pub(crate) struct Reject {}
+pub(crate) struct QtField { keep: bool }
+pub(crate) struct QtEnd { keep: bool }
 
 pub(crate) enum ParserState {
     NwLine(NwLine),
@@ -59,48 +356,69 @@ pub(crate) enum ParserState {
     Space(Space),
     Field(Field),
     SkField(SkField),
+    Cmt(Cmt),
+    Reject(Reject),
+    QtField(QtField),
+    QtEnd(QtEnd),
 }
 
 impl ParserState {
     #[inline(always)]
-    pub(crate) fn next<RRP: RawReaderParse>(&self, buffer: &[u8], newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    pub(crate) fn next<RRP: RawReaderParse>(&self, buffer: &[u8], newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
         match self {
             ParserState::NwLine(ps) => ps.next(buffer, newline, core_data),
             ParserState::Delim(ps) => ps.next(buffer, newline, core_data),
             ParserState::Space(ps) => ps.next(buffer, newline, core_data),
             ParserState::Field(ps) => ps.next(buffer, newline, core_data),
             ParserState::SkField(ps) => ps.next(buffer, newline, core_data),
+            ParserState::Cmt(ps) => ps.next(newline, core_data),
+            ParserState::Reject(ps) => ps.next(buffer, newline, core_data),
+            ParserState::QtField(ps) => ps.next(buffer, newline, core_data),
+            ParserState::QtEnd(ps) => ps.next(buffer, newline, core_data),
         }
     }    
 }
 
 impl Parser for NwLine {
     #[inline(always)]
-    fn next<RRP: RawReaderParse>(&self, buffer: &[u8], newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    fn next<RRP: RawReaderParse>(&self, buffer: &[u8], newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
+        //We're always at the start of a line in this state
+        core_data.line_start = core_data.pos + core_data.offset;
+        core_data.line_buf.clear();
         let buf_val = buffer[core_data.offset];
-        if (buf_val == core_data.delim) & !core_data.delim_ws
+        //The '\n' of a "\r\n" finishes off the line before it, so we just move past it.
+        if (buf_val == b'\n') && core_data.after_cr(buffer) {
+            newline.next();
+            core_data.offset += 1;
+            Ok(ParserState::NwLine(NwLine{}))
+        }
+        else if (buf_val == core_data.delim) & !core_data.delim_ws
         {
             self.parse_delim(core_data)
         }
         else if (buf_val == b' ') | (buf_val == b'\t') {
-            return self.parse_whitespace(core_data);
+            self.parse_whitespace(core_data)
         }
         else if (buf_val == b'\n') | (buf_val == b'\r') | (buf_val == core_data.cmt) {
-            return self.parse_newline(newline, core_data);
+            self.parse_newline(newline, core_data)
         } else {
-            return self.parse_others(buf_val, core_data);
+            self.parse_others(buf_val, core_data)
         }
     }
 
     #[inline(always)]
-    fn parse_delim<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    fn parse_delim<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
         core_data.field_counter = 1;
+        if core_data.empty_fields {
+            core_data.empty_field()?;
+            core_data.field_counter += 1;
+        }
         core_data.offset += 1;
         Ok(ParserState::Delim(Delim{}))
     }
 
     #[inline(always)]
-    fn parse_whitespace<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    fn parse_whitespace<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
         if core_data.delim_ws {
             core_data.field_counter = 1;
             core_data.offset += 1;
@@ -113,78 +431,58 @@ impl Parser for NwLine {
     }
 
     #[inline(always)]
-    fn parse_newline<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
-        let val = newline.next();
-        core_data.offset = match val {
-            Some(val) => val + 1,
-            None => core_data.length,
-        };
-        core_data.fln += 1;
-        Ok(ParserState::NwLine(NwLine{}))
+    fn parse_newline<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
+        let state = core_data.skip_line(newline);
+        Ok(state)
     }
 
     #[inline(always)]
-    fn parse_comment<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    fn parse_comment<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
         self.parse_newline(newline, core_data)
     }
 
     #[inline(always)]
-    fn parse_others<RRP: RawReaderParse>(&self, buf_val: u8, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    fn parse_others<RRP: RawReaderParse>(&self, buf_val: u8, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
         core_data.field_counter = 1;
         core_data.offset += 1;
-        match &core_data.cols.len() {
-            0 => {
-                core_data.current_field = 1;
-                core_data.results.set_results(buf_val, core_data.field_counter);
-                Ok(ParserState::Field(Field{}))
-            }
-            _ => {
-                let pos = core_data.cols.iter().position(|&x| x == core_data.field_counter);
-                match pos {
-                    Some(x) => {
-                        core_data.current_field = x + 1;
-                        core_data.results.set_results(buf_val, core_data.current_field);
-                        Ok(ParserState::Field(Field{}))
-                    }
-                    None => {
-                        Ok(ParserState::SkField(SkField{}))
-                    }
-                }
-            }
-        }
+        Ok(core_data.start_field(buf_val))
     }
 }
 
 impl Parser for Delim {
     #[inline(always)]
-    fn next<RRP: RawReaderParse>(&self, buffer: &[u8], newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    fn next<RRP: RawReaderParse>(&self, buffer: &[u8], newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
         let buf_val = buffer[core_data.offset];
         if (buf_val == core_data.delim) & !core_data.delim_ws
         {
             self.parse_delim(core_data)
         }
         else if (buf_val == b' ') | (buf_val == b'\t') {
-            return self.parse_whitespace(core_data);
+            self.parse_whitespace(core_data)
         }
         else if (buf_val == b'\n') | (buf_val == b'\r') {
-            return self.parse_newline(newline, core_data);
+            self.parse_newline(newline, core_data)
         } 
         else if buf_val == core_data.cmt {
-            return self.parse_comment(newline, core_data);
+            self.parse_comment(newline, core_data)
         }
         else {
-            return self.parse_others(buf_val, core_data);
+            self.parse_others(buf_val, core_data)
         }
     }
 
     #[inline(always)]
-    fn parse_delim<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    fn parse_delim<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
+        if core_data.empty_fields {
+            core_data.empty_field()?;
+            core_data.field_counter += 1;
+        }
         core_data.offset += 1;
         Ok(ParserState::Delim(Delim{}))
     }
 
     #[inline(always)]
-    fn parse_whitespace<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    fn parse_whitespace<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
         if core_data.delim_ws {
             core_data.offset += 1;
             Ok(ParserState::Delim(Delim{}))
@@ -196,128 +494,84 @@ impl Parser for Delim {
     }
 
     #[inline(always)]
-    fn parse_newline<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
-        let val = newline.next();
-        core_data.offset = match val {
-            Some(val) => val + 1,
-            None => core_data.length,
-        };
-        core_data.fln += 1;
-    
+    fn parse_newline<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
         if core_data.delim_ws {
             core_data.field_counter -= 1;
-            if (core_data.field_counter != core_data.tot_fields) & (core_data.field_counter != 0) {
-                return Err(format_err!(
-                    "Newline (delim) Number of fields,{}, provided at line {} 
-                    is different than the initial field number of {}",
-                    core_data.field_counter,
-                    core_data.fln,
-                    core_data.tot_fields
-                ));
+            //A line made up of nothing but white space isn't a line of data
+            if core_data.field_counter > 0 {
+                core_data.end_line()?;
             }
-            core_data.field_counter = 0;
-            core_data.results.incr_num_lines();
+        } else if core_data.empty_fields {
+            core_data.empty_field()?;
+            core_data.end_line()?;
         } else {
-            return Err(format_err!(
-                "Number of fields provided at line {} 
-                ends with a delimiter instead of a field or white space",
-                core_data.fln
-            ));
+            return Err(trailing_delim_error(core_data));
         }
-        Ok(ParserState::NwLine(NwLine{}))
+        Ok(core_data.skip_line(newline))
     }
 
     #[inline(always)]
-    fn parse_comment<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
-        let val = newline.next();
-        core_data.offset = match val {
-            Some(val) => val + 1,
-            None => core_data.length,
-        };
-        core_data.fln += 1;
-
+    fn parse_comment<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
         if core_data.delim_ws {
             core_data.field_counter -= 1;
-            if (core_data.field_counter != core_data.tot_fields) & (core_data.field_counter != 0) {
-                return Err(format_err!(
-                    "Cmt (delim) Number of fields,{}, provided at line {} 
-                    is different than the initial field number of {}",
-                    core_data.field_counter,
-                    core_data.fln,
-                    core_data.tot_fields
-                ));
-            }
+            //A line made up of nothing but white space isn't a line of data
             if core_data.field_counter > 0 {
-                core_data.field_counter = 0;
-                core_data.results.incr_num_lines();
+                core_data.end_line()?;
             }
+        } else if core_data.empty_fields {
+            core_data.empty_field()?;
+            core_data.end_line()?;
         } else {
-            return Err(format_err!(
-                "Number of fields provided at line {} 
-                ends with a delimiter instead of a field or white space",
-                core_data.fln
-            ));
+            return Err(trailing_delim_error(core_data));
         }
-        Ok(ParserState::NwLine(NwLine{}))
+        Ok(core_data.skip_line(newline))
     }
 
     #[inline(always)]
-    fn parse_others<RRP: RawReaderParse>(&self, buf_val: u8, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    fn parse_others<RRP: RawReaderParse>(&self, buf_val: u8, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
         core_data.offset += 1;
-        match &core_data.cols.len() {
-            0 => {
-                core_data.current_field = core_data.field_counter;
-                core_data.results.set_results(buf_val, core_data.field_counter);
-                Ok(ParserState::Field(Field{}))
-            }
-            _ => {
-                let pos = core_data.cols.iter().position(|&x| x == core_data.field_counter);
-                match pos {
-                    Some(x) => {
-                        core_data.current_field = x + 1;
-                        core_data.results.set_results(buf_val, core_data.current_field);
-                        Ok(ParserState::Field(Field{}))
-                    }
-                    None => {
-                        Ok(ParserState::SkField(SkField{}))
-                    }
-                }
-            }
-        }
+        Ok(core_data.start_field(buf_val))
     }
 }
 
 impl Parser for Space {
     #[inline(always)]
-    fn next<RRP: RawReaderParse>(&self, buffer: &[u8], newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    fn next<RRP: RawReaderParse>(&self, buffer: &[u8], newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
         let buf_val = buffer[core_data.offset];
         if (buf_val == core_data.delim) & !core_data.delim_ws
         {
             self.parse_delim(core_data)
         }
         else if (buf_val == b' ') | (buf_val == b'\t') {
-            return self.parse_whitespace(core_data);
+            self.parse_whitespace(core_data)
         }
         else if (buf_val == b'\n') | (buf_val == b'\r') {
-            return self.parse_newline(newline, core_data);
+            self.parse_newline(newline, core_data)
         } 
         else if buf_val == core_data.cmt {
-            return self.parse_comment(newline, core_data);
+            self.parse_comment(newline, core_data)
         }
         else {
-            return self.parse_others(buf_val, core_data);
+            self.parse_others(buf_val, core_data)
         }
     }
 
     #[inline(always)]
-    fn parse_delim<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    fn parse_delim<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
+        //Only white space came before this delimiter, so if empty fields count then we've got one.
+        if core_data.empty_fields {
+            if core_data.field_counter == 0 {
+                core_data.field_counter = 1;
+            }
+            core_data.empty_field()?;
+        }
         core_data.field_counter += 1;
         core_data.offset += 1;
         Ok(ParserState::Delim(Delim{}))
     }
 
     #[inline(always)]
-    fn parse_whitespace<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    fn parse_whitespace<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
         if core_data.delim_ws {
             core_data.field_counter += 1;
             core_data.offset += 1;
@@ -330,92 +584,67 @@ impl Parser for Space {
     }
 
     #[inline(always)]
-    fn parse_newline<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
-        let val = newline.next();
-        core_data.offset = match val {
-            Some(val) => val + 1,
-            None => core_data.length,
-        };
-        core_data.fln += 1;
-        Ok(ParserState::NwLine(NwLine{}))
+    fn parse_newline<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
+        //If we came after a delimiter and empty fields count, then our line ended with an empty field
+        if core_data.empty_fields & (core_data.field_counter > 0) {
+            core_data.empty_field()?;
+            core_data.end_line()?;
+        }
+        let state = core_data.skip_line(newline);
+        Ok(state)
     }
 
     #[inline(always)]
-    fn parse_comment<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
-        let val = newline.next();
-        core_data.offset = match val {
-            Some(val) => val + 1,
-            None => core_data.length,
-        };
-        core_data.fln += 1;
-        Ok(ParserState::NwLine(NwLine{}))
+    fn parse_comment<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
+        self.parse_newline(newline, core_data)
     }
 
     #[inline(always)]
-    fn parse_others<RRP: RawReaderParse>(&self, buf_val: u8, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    fn parse_others<RRP: RawReaderParse>(&self, buf_val: u8, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
         core_data.offset += 1;
         //The case where we start out with spaces before our 1st field at the start of a line
         if core_data.field_counter == 0 {
             core_data.field_counter += 1;
         }
-        match &core_data.cols.len() {
-            0 => {
-                core_data.current_field = core_data.field_counter;
-                core_data.results.set_results(buf_val, core_data.field_counter);
-                Ok(ParserState::Field(Field{}))
-            }
-            _ => {
-                let pos = core_data.cols.iter().position(|&x| x == core_data.field_counter);
-                match pos {
-                    Some(x) => {
-                        core_data.current_field = x + 1;
-                        core_data.results.set_results(buf_val, core_data.current_field);
-                        Ok(ParserState::Field(Field{}))
-                    }
-                    None => {
-                        Ok(ParserState::SkField(SkField{}))
-                    }
-                }
-            }
-        }
+        Ok(core_data.start_field(buf_val))
     }
 }
 
 impl Parser for Field {
     #[inline(always)]
-    fn next<RRP: RawReaderParse>(&self, buffer: &[u8], newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    fn next<RRP: RawReaderParse>(&self, buffer: &[u8], newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
         let buf_val = buffer[core_data.offset];
         if (buf_val == core_data.delim) & !core_data.delim_ws
         {
             self.parse_delim(core_data)
         }
         else if (buf_val == b' ') | (buf_val == b'\t') {
-            return self.parse_whitespace(core_data);
+            self.parse_whitespace(core_data)
         }
         else if (buf_val == b'\n') | (buf_val == b'\r') {
-            return self.parse_newline(newline, core_data);
+            self.parse_newline(newline, core_data)
         } 
         else if buf_val == core_data.cmt {
-            return self.parse_comment(newline, core_data);
+            self.parse_comment(newline, core_data)
         }
         else {
-            return self.parse_others(buf_val, core_data);
+            self.parse_others(buf_val, core_data)
         }
     }
 
     #[inline(always)]
-    fn parse_delim<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    fn parse_delim<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
+        core_data.end_field()?;
         core_data.field_counter += 1;
         core_data.offset += 1;
-        core_data.results.set_index(core_data.current_field);
         Ok(ParserState::Delim(Delim{}))
     }
 
     #[inline(always)]
-    fn parse_whitespace<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    fn parse_whitespace<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
         if core_data.delim_ws {
+            core_data.end_field()?;
             core_data.field_counter += 1;
-            core_data.results.set_index(core_data.current_field);
             core_data.offset += 1;
             Ok(ParserState::Delim(Delim{}))
         }
@@ -426,53 +655,21 @@ impl Parser for Field {
     }
 
     #[inline(always)]
-    fn parse_newline<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
-        let val = newline.next();
-        core_data.offset = match val {
-            Some(val) => val + 1,
-            None => core_data.length,
-        };
-        core_data.fln += 1;
-        core_data.results.set_index(core_data.current_field);
-        if core_data.field_counter != core_data.tot_fields {
-            return Err(format_err!(
-                "Newline (field) Number of fields,{}, provided at line {} 
-                is different than the initial field number of {}",
-                core_data.field_counter,
-                core_data.fln,
-                core_data.tot_fields
-            ));
-        }
-        core_data.results.incr_num_lines();
-        core_data.field_counter = 0;
-        Ok(ParserState::NwLine(NwLine{}))
-    }
-
-    #[inline(always)]
-    fn parse_comment<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
-        let val = newline.next();
-        core_data.offset = match val {
-            Some(val) => val + 1,
-            None => core_data.length,
-        };
-        core_data.fln += 1;
-        core_data.results.set_index(core_data.current_field);
-        if core_data.field_counter != core_data.tot_fields {
-            return Err(format_err!(
-                "Cmt (field) Number of fields,{}, provided at line {} 
-                is different than the initial field number of {}",
-                core_data.field_counter,
-                core_data.fln,
-                core_data.tot_fields
-            ));
-        }
-        core_data.results.incr_num_lines();
-        core_data.field_counter = 0;
-        Ok(ParserState::NwLine(NwLine{}))
+    fn parse_newline<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
+        core_data.end_field()?;
+        core_data.end_line()?;
+        Ok(core_data.skip_line(newline))
     }
 
     #[inline(always)]
-    fn parse_others<RRP: RawReaderParse>(&self, buf_val: u8, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    fn parse_comment<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
+        core_data.end_field()?;
+        core_data.end_line()?;
+        Ok(core_data.skip_line(newline))
+    }
+
+    #[inline(always)]
+    fn parse_others<RRP: RawReaderParse>(&self, buf_val: u8, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
         core_data.offset += 1;
         core_data.results.set_results(buf_val, core_data.current_field);
         Ok(ParserState::Field(Field{}))
@@ -483,35 +680,35 @@ impl Parser for Field {
 
 impl Parser for SkField {
     #[inline(always)]
-    fn next<RRP: RawReaderParse>(&self, buffer: &[u8], newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    fn next<RRP: RawReaderParse>(&self, buffer: &[u8], newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
         let buf_val = buffer[core_data.offset];
         if (buf_val == core_data.delim) & !core_data.delim_ws
         {
             self.parse_delim(core_data)
         }
         else if (buf_val == b' ') | (buf_val == b'\t') {
-            return self.parse_whitespace(core_data);
+            self.parse_whitespace(core_data)
         }
         else if (buf_val == b'\n') | (buf_val == b'\r') {
-            return self.parse_newline(newline, core_data);
+            self.parse_newline(newline, core_data)
         } 
         else if buf_val == core_data.cmt {
-            return self.parse_comment(newline, core_data);
+            self.parse_comment(newline, core_data)
         }
         else {
-            return self.parse_others(buf_val, core_data);
+            self.parse_others(buf_val, core_data)
         }
     }
 
     #[inline(always)]
-    fn parse_delim<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    fn parse_delim<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
         core_data.field_counter += 1;
         core_data.offset += 1;
         Ok(ParserState::Delim(Delim{}))
     }
 
     #[inline(always)]
-    fn parse_whitespace<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    fn parse_whitespace<RRP: RawReaderParse>(&self, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
         if core_data.delim_ws {
             core_data.offset += 1;
             core_data.field_counter += 1;
@@ -524,53 +721,115 @@ impl Parser for SkField {
     }
 
     #[inline(always)]
-    fn parse_newline<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
-        let val = newline.next();
-        core_data.offset = match val {
-            Some(val) => val + 1,
-            None => core_data.length,
-        };
-        core_data.fln += 1;
-        if core_data.field_counter != core_data.tot_fields {
-            return Err(format_err!(
-                "Newline (skip field) Number of fields,{}, provided at line {} 
-                is different than the initial field number of {}",
-                core_data.field_counter,
-                core_data.fln,
-                core_data.tot_fields
-            ));
-        }
-        core_data.results.incr_num_lines();
-        core_data.field_counter = 0;
-        Ok(ParserState::NwLine(NwLine{}))
-    }
-
-    #[inline(always)]
-    fn parse_comment<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
-        let val = newline.next();
-        core_data.offset = match val {
-            Some(val) => val + 1,
-            None => core_data.length,
-        };
-        core_data.fln += 1;
-        if core_data.field_counter != core_data.tot_fields {
-            return Err(format_err!(
-                "Cmt (skip field) Number of fields,{}, provided at line {} 
-                is different than the initial field number of {}",
-                core_data.field_counter,
-                core_data.fln,
-                core_data.tot_fields
-            ));
-        }
-        core_data.results.incr_num_lines();
-        core_data.field_counter = 0;
-        Ok(ParserState::NwLine(NwLine{}))
+    fn parse_newline<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
+        core_data.end_line()?;
+        Ok(core_data.skip_line(newline))
     }
 
     #[inline(always)]
-    fn parse_others<RRP: RawReaderParse>(&self, _buf_val: u8, core_data: &mut CoreData<RRP>) -> Result<ParserState, Error> {
+    fn parse_comment<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
+        core_data.end_line()?;
+        Ok(core_data.skip_line(newline))
+    }
+
+    #[inline(always)]
+    fn parse_others<RRP: RawReaderParse>(&self, _buf_val: u8, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
         core_data.offset += 1;
         Ok(ParserState::SkField(SkField{}))
     }
 }
 
+impl Cmt {
+    ///We're in the middle of a line that has already been accounted for, and it was split across our buffers.
+    ///So, all we need to do is find the end of it. The file line number was already incremented when we
+    ///first started skipping this line.
+    #[inline(always)]
+    fn next<RRP: RawReaderParse>(&self, newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
+        match newline.next() {
+            Some(val) => {
+                core_data.offset = val + 1;
+                Ok(ParserState::NwLine(NwLine{}))
+            }
+            None => {
+                core_data.offset = core_data.length;
+                Ok(ParserState::Cmt(Cmt{}))
+            }
+        }
+    }
+}
+
+impl Reject {
+    ///We're in the middle of a line that's being skipped over since it had an error, and it was split across our buffers.
+    ///So, we need to find the end of it while saving off the rest of its contents in case we're reporting it.
+    ///The file line number was already incremented when we first started skipping this line.
+    #[inline(always)]
+    fn next<RRP: RawReaderParse>(&self, buffer: &[u8], newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
+        let start = core_data.offset;
+        match newline.next() {
+            Some(val) => {
+                core_data.offset = val + 1;
+                if let Some(mut rejected) = core_data.rejected.take() {
+                    rejected.bytes.extend_from_slice(&buffer[start..val]);
+                    core_data.add_rejected(rejected);
+                }
+                Ok(ParserState::NwLine(NwLine{}))
+            }
+            None => {
+                core_data.offset = core_data.length;
+                if let Some(rejected) = core_data.rejected.as_mut() {
+                    rejected.bytes.extend_from_slice(&buffer[start..]);
+                }
+                Ok(ParserState::Reject(Reject{}))
+            }
+        }
+    }
+}
+
+impl QtField {
+    ///We're inside of a quoted field, so everything up until the next quote character is a part of the field.
+    ///That includes any delimiters, comment characters, and newlines. A newline still needs to be accounted for in
+    ///our newline iterator and file line number, since the rest of the parser expects them to be up to date.
+    ///Just like outside of quotes, a "\r\n" is only a single line ending.
+    #[inline(always)]
+    fn next<RRP: RawReaderParse>(&self, buffer: &[u8], newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
+        let buf_val = buffer[core_data.offset];
+        if buf_val == core_data.quote {
+            core_data.offset += 1;
+            return Ok(ParserState::QtEnd(QtEnd{ keep: self.keep }));
+        }
+        if (buf_val == b'\n') | (buf_val == b'\r') {
+            newline.next();
+            if (buf_val == b'\r') || !core_data.after_cr(buffer) {
+                core_data.fln += 1;
+            }
+        }
+        core_data.offset += 1;
+        if self.keep {
+            core_data.results.set_results(buf_val, core_data.current_field);
+        }
+        Ok(ParserState::QtField(QtField{ keep: self.keep }))
+    }
+}
+
+impl QtEnd {
+    ///We've just come across a quote character within a quoted field. If it's followed by another quote character
+    ///then it was an escaped quote, which is saved off as a single quote, and we're still inside of our field.
+    ///Otherwise, the quoted part of our field is over and the rest of it is handled the same as an unquoted field.
+    #[inline(always)]
+    fn next<RRP: RawReaderParse>(&self, buffer: &[u8], newline: &mut Memchr2, core_data: &mut CoreData<RRP>) -> Result<ParserState, ReaderError> {
+        let buf_val = buffer[core_data.offset];
+        if buf_val == core_data.quote {
+            core_data.offset += 1;
+            if self.keep {
+                core_data.results.set_results(buf_val, core_data.current_field);
+            }
+            Ok(ParserState::QtField(QtField{ keep: self.keep }))
+        }
+        else if self.keep {
+            Field{}.next(buffer, newline, core_data)
+        }
+        else {
+            SkField{}.next(buffer, newline, core_data)
+        }
+    }
+}
@@ -0,0 +1,224 @@
+// This file is a part of the Rust Data Reader Library
+// Copyright 2018 Robert Carson
+//
+//    Licensed under the Apache License, Version 2.0 (the "License");
+//    you may not use this file except in compliance with the License.
+//    You may obtain a copy of the License at
+//
+//        http://www.apache.org/licenses/LICENSE-2.0
+//
+//    Unless required by applicable law or agreed to in writing, software
+//    distributed under the License is distributed on an "AS IS" BASIS,
+//    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//    See the License for the specific language governing permissions and
+//    limitations under the License.
+use super::*;
+
+use std::fs::File;
+use memmap::MmapOptions;
+use rayon::prelude::*;
+
+use super::parser::{check_num_lines, get_cols, get_delim, get_missing, get_names, parse_buffer, parse_eof, read_header_names};
+use super::parser_core::{check_cols, NwLine, ParserState, CoreData};
+use super::parser_utility::{skip_header_lines, split_footer};
+#[cfg(any(feature = "gzip", feature = "zstd", feature = "bzip2", feature = "xz"))]
+use super::compression::Compression;
+
+//The smallest chunk of data that we'll hand off to a thread. Anything smaller than this and
+//the cost of stitching the results back together isn't worth it.
+const MIN_CHUNK_SIZE: usize = 1 << 20;
+
+///par_parse_txt is the parallel version of parse_txt. The file is memory mapped and split up into chunks at newline boundaries.
+///Each chunk is run through the parser on rayon's thread pool, and the raw results from each chunk are then stitched back together in order.
+///Since every chunk starts at the beginning of a line, a chunk can never start part way through a commented line. If a malformed field is
+///seen the error contains the same line number that parse_txt would have given, and if several chunks have malformed fields the error from the
+///earliest one in the file is returned. The same goes for the byte offsets in the error.
+///
+///Compressed files can't be split up, so they're just stream decompressed and parsed like they would be in parse_txt.
+///
+///Input -
+///
+/// f is simply the location of the file.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a RawReaderResults structure or an error.
+pub fn par_parse_txt<T>(f: &str, params: &ReaderParams) -> Result<T, ReaderError>
+where
+    T: RawReaderParse + RawReaderAppend + Send,
+{
+    let file = File::open(f)?;
+    let buffer = unsafe { MmapOptions::new().map(&file)? };
+
+    #[cfg(any(feature = "gzip", feature = "zstd", feature = "bzip2", feature = "xz"))]
+    if let Some(compression) = Compression::detect(f, &buffer[..]) {
+        return parse_reader(compression.decoder(&buffer[..])?, params);
+    }
+
+    par_parse_bytes(&buffer[..], params)
+}
+
+///par_parse_bytes is the parallel version of parse_bytes. It works the same as par_parse_txt except the data already lives in memory.
+///
+///If max_rows or quote is used the data is parsed with parse_bytes instead. We'd need to know where each line lives before we could split
+///up the work, and a quoted field can have newlines within it.
+///
+///If row_errors is set to skip over malformed lines or missing_values is used, the first chunk is parsed on its own before the rest,
+///since its first line might be skipped or have empty fields and we'd then not know how many fields the rest of the chunks should have.
+///Any chunks without a line of data at the start of our data are also parsed on their own until we've found that first line.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a RawReaderResults structure or an error.
+pub fn par_parse_bytes<T>(bytes: &[u8], params: &ReaderParams) -> Result<T, ReaderError>
+where
+    T: RawReaderParse + RawReaderAppend + Send,
+{
+    if params.max_rows.is_some() | params.quote.is_some() {
+        return parse_bytes(bytes, params);
+    }
+
+    //our comment string
+    //If we don't have one then we just say a comment is a newline character.
+    let cmt = params.comments.unwrap_or(b'\n');
+
+    let (delim, delim_ws) = get_delim(params);
+
+    //File line number used for Error information
+    let mut fln = 0;
+
+    //The header and footer are both removed before we split our data up
+    let mut data = bytes;
+    let (sk_h, pos) = skip_header_lines(&mut data, &mut fln, cmt, params.skip_header.unwrap_or_default())?;
+    let (names, nm_h, nm_pos) = read_header_names(&mut data, &mut fln, params)?;
+    let pos = pos + nm_pos;
+    let (data, num_held_lines) = split_footer(data, cmt, params.skip_footer.unwrap_or_default());
+
+    let cols = get_cols(params, &names)?;
+    let missing = get_missing(params, &names)?;
+    let chunks = split_chunks(data, rayon::current_num_threads());
+
+    //The line number that each chunk starts on. Our parser counts each newline character as a new line except for the
+    //'\n' of a "\r\n", so we need to do the same here for our error line numbers to match up. Our chunks always end
+    //right after a '\n', so a "\r\n" is never split up between them.
+    let num_chunk_lines: Vec<usize> = chunks
+        .par_iter()
+        .map(|chunk| bytecount::count(chunk, b'\n') + bytecount::count(chunk, b'\r') - memchr::memmem::find_iter(chunk, b"\r\n").count())
+        .collect();
+    let flns: Vec<usize> = num_chunk_lines
+        .iter()
+        .scan(fln, |acc, &x| {
+            let start = *acc;
+            *acc += x;
+            Some(start)
+        })
+        .collect();
+    //The byte offset that each chunk starts at, so errors can also tell us where they are within our data.
+    let poss: Vec<usize> = chunks
+        .iter()
+        .scan(pos, |acc, chunk| {
+            let start = *acc;
+            *acc += chunk.len();
+            Some(start)
+        })
+        .collect();
+
+    let parse_chunk = |chunk: &[u8], tot_fields: usize, chunk_fln: usize, chunk_pos: usize| -> Result<(T, usize, usize), ReaderError> {
+        let nfields = if !cols.is_empty() { cols.len() } else { tot_fields };
+        let mut results: T = T::new(nfields, 0);
+        results.set_params(params);
+        let mut core_data = CoreData::new(params, &cols, missing.as_ref(), &mut results, tot_fields, chunk_fln, chunk_pos);
+        let state = parse_buffer(chunk, ParserState::NwLine(NwLine{}), &mut core_data, usize::MAX)?;
+        parse_eof(state, &mut core_data, usize::MAX)?;
+        let (tot_fields, num_rejected) = (core_data.tot_fields, core_data.num_rejected);
+        Ok((results, tot_fields, num_rejected))
+    };
+
+    //Every chunk needs to know how many fields to expect up front. Normally we find that from the first line of data,
+    //but if that line could end up being skipped over or has empty fields in it then the first chunk has to be parsed before we can know it.
+    //If the first chunk doesn't have any lines of data, such as a long block of comments, we keep going one chunk at a time until one does.
+    let mut first_chunks = Vec::new();
+    let tot_fields = if (params.row_errors != RowErrors::Fail) | params.missing_values.is_some() {
+        let mut tot_fields = 0;
+        while tot_fields == 0 && first_chunks.len() < chunks.len() {
+            let ichunk = first_chunks.len();
+            let first_chunk = parse_chunk(chunks[ichunk], 0, flns[ichunk], poss[ichunk])?;
+            tot_fields = first_chunk.1;
+            first_chunks.push(Ok(first_chunk));
+        }
+        tot_fields
+    } else {
+        let tot_fields = count_num_fields(&mut &data[..], cmt, delim, delim_ws);
+        if tot_fields > 0 {
+            check_cols(&cols, tot_fields)?;
+        }
+        tot_fields
+    };
+    let nfields = if !cols.is_empty() { cols.len() } else { tot_fields };
+    let skip = first_chunks.len();
+
+    let chunk_results: Vec<Result<(T, usize, usize), ReaderError>> = first_chunks
+        .into_iter()
+        .chain(
+            chunks[skip..]
+                .par_iter()
+                .zip(flns[skip..].par_iter().zip(poss[skip..].par_iter()))
+                .map(|(chunk, (&chunk_fln, &chunk_pos))| parse_chunk(chunk, tot_fields, chunk_fln, chunk_pos))
+                .collect::<Vec<_>>(),
+        )
+        .collect();
+
+    //We stitch everything back together in order, so if any chunk failed the earliest error in the data is the one returned.
+    let mut results: T = T::new(nfields, 0);
+    results.set_params(params);
+    results.set_names(get_names(names, &cols, params.row_format));
+    let mut num_rejected = 0;
+    for chunk_result in chunk_results {
+        match chunk_result {
+            Ok((chunk, _, chunk_rejected)) => {
+                results.append(chunk);
+                num_rejected += chunk_rejected;
+            }
+            //A chunk only knows about its own rows, so the rows from the chunks before it need to be added on.
+            Err(mut err) => {
+                err.offset_row(results.get_num_lines());
+                return Err(err);
+            }
+        }
+    }
+
+    //Now that we've gone through our data we know how many readable lines it had, so
+    //we can make sure that the parameters we provided were valid for it.
+    check_num_lines(sk_h + nm_h + results.get_num_lines() + num_rejected + num_held_lines, params)?;
+
+    Ok(results)
+}
+
+///Splits our data up into roughly num_chunks pieces, where each piece ends right after a newline character.
+///Chunks are never smaller than MIN_CHUNK_SIZE unless they're the last bit of our data.
+fn split_chunks(data: &[u8], num_chunks: usize) -> Vec<&[u8]> {
+    let chunk_size = (data.len() / num_chunks.max(1)).max(MIN_CHUNK_SIZE);
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while start < data.len() {
+        let end = if start + chunk_size >= data.len() {
+            data.len()
+        } else {
+            match memchr::memchr(b'\n', &data[start + chunk_size..]) {
+                Some(val) => start + chunk_size + val + 1,
+                None => data.len(),
+            }
+        };
+        chunks.push(&data[start..end]);
+        start = end;
+    }
+    chunks
+}
@@ -14,9 +14,11 @@
 //    limitations under the License.
 
 use memchr::memchr2_iter;
-use std::io::{BufRead};
+use std::collections::VecDeque;
+use std::io::{BufRead, Read};
 
-use super::*;
+use super::{Names, BUF_SIZE};
+use super::reader_error::ReaderError;
 
 ///A private function that counts the number of lines that match a specified character specified to it.
 ///It is assumed that this character only appears once per line.
@@ -35,7 +37,7 @@ pub fn read_num_file_tot_lines<R: BufRead>(reader: &mut R) -> usize {
         count += count_lines(&buffer[0..length], b'\n');
         //We now need to consume everything in our buffer, so it's marked off as no longer being needed
         reader.consume(length);
-        if length < BUF_SIZE {
+        if length == 0 {
             break;
         }
     }
@@ -45,15 +47,20 @@ pub fn read_num_file_tot_lines<R: BufRead>(reader: &mut R) -> usize {
 ///It simply reads all of the lines in the file when an end of line is denoted by \n or \r.
 ///A comment character is provided and if it is seen then before any nonwhite space the line is not counted in the total.
 pub fn read_num_file_lines<R: BufRead>(reader: & mut R, com: u8) -> usize {
+    count_file_lines(reader, com).unwrap()
+}
+
+///The same as read_num_file_lines except that any errors from reading in the data are returned rather than panicking.
+pub(crate) fn count_file_lines<R: BufRead>(reader: & mut R, com: u8) -> std::io::Result<usize> {
     let mut count = 0;
-    //We're explicitly using the raw bytes here
-    // let mut reader = BufReader::with_capacity(BUF_SIZE, f);
+    //Whether or not the last buffer ended part way through a line that we still need to skip over
+    let mut in_line = false;
     //We loop over until the file has been completely read
     loop {
         //We first find the length of our buffer
         let length = {
             //We fill the buffer up. Our buffer is mutable which is why it's in this block
-            let buffer = reader.fill_buf().unwrap();
+            let buffer = reader.fill_buf()?;
             //We're now going to use an explicit loop.
             //I know this isn't idiomatic rust, but I couldn't really see a good way of skipping my iterator
             //to a location of my choosing.
@@ -66,29 +73,29 @@ pub fn read_num_file_lines<R: BufRead>(reader: & mut R, com: u8) -> usize {
             //Keeping it old school with some nice wild loops
             while i < length {
                 //Here's where the main magic occurs
+                //If we're in the middle of a line we skip to the start of the next line.
                 //If we come across a space or tab we move to the next item in the buffer
                 //If we come across a newline character we advance our iterator and move onto the
                 //next index essentially
                 //If we come across a comment character first (white spaces aren't counted) we completely skip the line
                 //If we come across any other character first (white spaces aren't counted) we increment our line counter
                 //and then skip the rest of the contents of the line.
-                //If we no longer have an item in our newline iterator we're done with everything in our buffer, and so
-                //we can exit the loop.
-                if (buffer[i] == b' ') | (buffer[i] == b'\t') {
+                //If we no longer have an item in our newline iterator the line continues on into the next buffer.
+                if in_line {
+                    match newline.next() {
+                        Some(val) => {
+                            i = val + 1;
+                            in_line = false;
+                        }
+                        None => i = length,
+                    };
+                } else if (buffer[i] == b' ') | (buffer[i] == b'\t') {
                     i += 1;
                 } else if (buffer[i] == b'\n') | (buffer[i] == b'\r') | (buffer[i] == com) {
-                    let val = newline.next();
-                    i = match val {
-                        Some(val) => val + 1,
-                        None => length,
-                    };
+                    in_line = true;
                 } else {
                     count += 1;
-                    let val = newline.next();
-                    i = match val {
-                        Some(val) => val + 1,
-                        None => length,
-                    };
+                    in_line = true;
                 }
             }
             //Pass off our length to set our length outside of this block of code
@@ -96,13 +103,13 @@ pub fn read_num_file_lines<R: BufRead>(reader: & mut R, com: u8) -> usize {
         };
         //We now need to consume everything in our buffer, so it's marked off as no longer being needed
         reader.consume(length);
-        //If our length is less than our fixed buffer size we've reached the end of our file and can now exit.
-        if length < BUF_SIZE {
+        //If our buffer came back empty we've reached the end of our file and can now exit.
+        if length == 0 {
             break;
         }
     }
     //Finally, we return our line count to the main code.
-    count
+    Ok(count)
 }
 
 /// Public crate function that skips some number of given:
@@ -110,18 +117,25 @@ pub fn read_num_file_lines<R: BufRead>(reader: & mut R, com: u8) -> usize {
 /// fln - the current line that the reader is on
 /// cmt - the comment character as a u8 character 
 /// sk_h - the number of lines that we've want to skip not counting the comment character
-pub(crate) fn skip_header_lines<R: BufRead>(reader: & mut R, fln: &mut usize, cmt: u8, sk_h: usize) {
+/// It returns the number of lines that were skipped which is only less than sk_h if we ran out of data
+/// along with the number of bytes that were skipped.
+pub(crate) fn skip_header_lines<R: BufRead>(reader: & mut R, fln: &mut usize, cmt: u8, sk_h: usize) -> Result<(usize, usize), ReaderError> {
+    let mut count = 0;
+    let mut pos = 0;
     //If we skip any header lines then we need to skip forward through the file by
     //the correct number of lines when not taking into account commented lines.
     if sk_h > 0 {
-        let mut count = 0;
+        //Whether or not we're still part way through a line that we need to skip over
+        let mut in_line = false;
+        //Whether or not the last line we skipped ended with a '\r', since a '\n' right after it is a part of the same line ending
+        let mut after_cr = false;
 
         //We loop over until we've skipped over the desired number of lines
         loop {
             //We first find the length of our buffer
-            let length = {
+            let (length, consumed) = {
                 //We fill the buffer up. Our buffer is mutable which is why it's in this block
-                let buffer = reader.fill_buf().unwrap();
+                let buffer = reader.fill_buf()?;
                 //We're now going to use an explicit loop.
                 //I know this isn't idiomatic rust, but I couldn't really see a good way of skipping my iterator
                 //to a location of my choosing.
@@ -134,48 +148,152 @@ pub(crate) fn skip_header_lines<R: BufRead>(reader: & mut R, fln: &mut usize, cm
                 //Keeping it old school with some nice wild loops
                 while i < length {
                     //Here's where the main magic occurs
+                    //If we're in the middle of a line we skip to the start of the next line.
+                    //If we've reached the number of lines to skip we stop right at the start of the next line.
                     //If we come across a space or tab we move to the next item in the buffer
-                    //If we come across a newline character we advance our iterator and move onto the
-                    //next index essentially
-                    //If we come across a comment character first (white spaces aren't counted) we completely skip the line
+                    //If we come across a newline or comment character first (white spaces aren't counted) we skip the line
                     //If we come across any other character first (white spaces aren't counted) we increment our line counter
                     //and then skip the rest of the contents of the line.
-                    //If we no longer have an item in our newline iterator we're done with everything in our buffer, and so
-                    //we can exit the loop.
-                    if (buffer[i] == b' ') | (buffer[i] == b'\t') {
+                    //If we no longer have an item in our newline iterator the line continues on into the next buffer.
+                    if in_line {
+                        match newline.next() {
+                            Some(val) => {
+                                i = val + 1;
+                                in_line = false;
+                                after_cr = buffer[val] == b'\r';
+                                *fln += 1;
+                            }
+                            None => i = length,
+                        };
+                    } else if after_cr {
+                        after_cr = false;
+                        if buffer[i] == b'\n' {
+                            newline.next();
+                            i += 1;
+                        }
+                    } else if count == sk_h {
+                        break;
+                    } else if (buffer[i] == b' ') | (buffer[i] == b'\t') {
                         i += 1;
                     } else if (buffer[i] == b'\n') | (buffer[i] == b'\r') | (buffer[i] == cmt) {
-                        let val = newline.next();
-                        i = match val {
-                            Some(val) => val + 1,
-                            None => length + 1,
-                        };
-                        *fln += 1;
+                        in_line = true;
                     } else {
                         count += 1;
-                        let val = newline.next();
-                        i = match val {
-                            Some(val) => val + 1,
-                            None => length + 1,
-                        };
-                        *fln += 1;
-                    }
-                    //Here we're checking to see if we've reached the number of lines to skip or not
-                    if count == sk_h {
-                        break;
+                        in_line = true;
                     }
                 }
-                //Pass off our length to set our length outside of this block of code
-                i - 1
+                //Pass off our lengths to set them outside of this block of code
+                (length, i)
             };
-            //We now need to consume everything upto "length" in our buffer, so it's marked off as no longer being needed
-            reader.consume(length);
-            //If we've skipped over the desired number of lines we can exit the loop.
-            if count == sk_h || length < BUF_SIZE {
+            //We now need to consume everything upto "consumed" in our buffer, so it's marked off as no longer being needed
+            reader.consume(consumed);
+            pos += consumed;
+            //If we've skipped over the desired number of lines or reached the end of the file we can exit the loop.
+            if (count == sk_h && !in_line && !after_cr) || length == 0 {
                 break;
             }
         }
     }
+    Ok((count, pos))
+}
+
+/// Public crate function that reads in the names of our columns, which is done right after the header lines are skipped:
+/// reader - a mutable reference to a type that implements the BufRead trait
+/// fln - the current line that the reader is on
+/// cmt - the comment character as a u8 character
+/// delim - the delimiter character as a u8 character that separates our names
+/// delim_ws - whether or not our delimiter is any white space character that is not a \n or \r character
+/// quote - the quote character if our names can be quoted
+/// names - where our names come from
+/// It returns the names along with the number of lines of data that were read in, which is 1 if the names came from a header line,
+/// and the number of bytes that were read in.
+/// If we run out of data before finding our names a ReaderError::InvalidParams error is returned.
+pub(crate) fn read_names<R: BufRead>(reader: &mut R, fln: &mut usize, cmt: u8, delim: u8, delim_ws: bool, quote: Option<u8>, names: Names) -> Result<(Vec<String>, usize, usize), ReaderError> {
+    let mut line = Vec::<u8>::new();
+    //The most recent commented line that we've come across
+    let mut comment = None;
+    let mut pos = 0;
+
+    loop {
+        //A commented line only holds our names if it's right before our data,
+        //so we have to take a look at each line before we read it in.
+        if names == Names::Comment {
+            let buffer = reader.fill_buf()?;
+            if buffer.is_empty() | is_data_line(buffer, cmt) {
+                break;
+            }
+        }
+
+        line.clear();
+        let length = read_line(reader, &mut line)?;
+        if length == 0 {
+            break;
+        }
+        pos += length;
+        if let Some(b'\n' | b'\r') = line.last() {
+            *fln += 1;
+        }
+
+        //Anything after a comment character on our line isn't a part of our names
+        let start = line.iter().position(|&x| (x != b' ') & (x != b'\t')).unwrap_or(line.len());
+        let end = memchr::memchr(cmt, &line[start..]).map_or(line.len(), |x| start + x);
+        match names {
+            Names::Header => {
+                if is_data_line(&line, cmt) {
+                    return Ok((split_names(&line[..end], delim, delim_ws, quote), 1, pos));
+                }
+            }
+            Names::Comment => {
+                if end < line.len() {
+                    let cmt_end = memchr::memchr(cmt, &line[end + 1..]).map_or(line.len(), |x| end + 1 + x);
+                    comment = Some(split_names(&line[end + 1..cmt_end], delim, delim_ws, quote));
+                }
+            }
+        }
+    }
+
+    match comment {
+        Some(names) if !names.is_empty() => Ok((names, 0, pos)),
+        _ => Err(ReaderError::InvalidParams {
+            field: "names",
+            reason: match names {
+                Names::Header => String::from("couldn't find a line with the column names in it"),
+                Names::Comment => String::from("couldn't find a commented line with the column names right before the data"),
+            },
+        }),
+    }
+}
+
+///Splits up a line into the names of our columns with any whitespace around each name removed.
+///If we have a quote character, delimiters within quotes are a part of the name and the quotes are removed.
+fn split_names(line: &[u8], delim: u8, delim_ws: bool, quote: Option<u8>) -> Vec<String> {
+    //Our line might still have its newline characters at the end of it
+    let end = line.iter().rposition(|&x| (x != b'\n') & (x != b'\r')).map_or(0, |x| x + 1);
+    let mut names = Vec::<Vec<u8>>::new();
+    let mut name = Vec::<u8>::new();
+    let mut in_quotes = false;
+    let mut iter = line[..end].iter().peekable();
+    while let Some(&x) = iter.next() {
+        if Some(x) == quote {
+            //A doubled quote within quotes is an escaped quote
+            if in_quotes & (iter.peek() == Some(&&x)) {
+                name.push(x);
+                iter.next();
+            } else {
+                in_quotes = !in_quotes;
+            }
+        } else if !in_quotes & (if delim_ws { (x == b' ') | (x == b'\t') } else { x == delim }) {
+            names.push(std::mem::take(&mut name));
+        } else {
+            name.push(x);
+        }
+    }
+    names.push(name);
+    names
+        .iter()
+        .map(|x| String::from(String::from_utf8_lossy(x).trim()))
+        .filter(|x| !delim_ws | !x.is_empty())
+        .collect()
 }
 
 /// Counts the total number of fields within the file
@@ -190,8 +308,10 @@ pub fn count_num_fields<R: BufRead>(reader:&mut R, cmt: u8, delim: u8, delim_ws:
     enum ParseState {CmtNwLine, Field, Space, Delim}
 
     let mut state = ParseState::CmtNwLine;
+    //Whether or not the last buffer ended part way through a comment line that we still need to skip over
+    let mut in_line = false;
 
-    //We loop over until we've skipped over the desired number of lines
+    //We loop over until we've found the end of the first line of data
     loop {
         //We first find the length of our buffer
         let length = {
@@ -209,15 +329,24 @@ pub fn count_num_fields<R: BufRead>(reader:&mut R, cmt: u8, delim: u8, delim_ws:
             //Keeping it old school with some nice wild loops
             while i < length {
                 //Here's where the main magic occurs
-                //If we come across a space or tab we move to the next item in the buffer
-                //If we come across a newline character we advance our iterator and move onto the
-                //next index essentially
-                //If we come across a comment character first (white spaces aren't counted) we completely skip the line
-                //If we come across any other character first (white spaces aren't counted) we increment our line counter
-                //and then skip the rest of the contents of the line.
-                //If we no longer have an item in our newline iterator we're done with everything in our buffer, and so
-                //we can exit the loop.
-                if (buffer[i] == delim) & !delim_ws {
+                //If we're in the middle of a commented line we skip to the start of the next line.
+                //If we come across a delimiter we move to the next field.
+                //If we come across a space or tab we either treat it as a delimiter or just move on
+                //to the next item in the buffer
+                //If we come across a newline or comment character before any field we skip the line.
+                //If we come across it after a field then we've found the end of our first line and can return the field count.
+                //If we come across any other character we're in a field.
+                //If we no longer have an item in our newline iterator the line continues on into the next buffer.
+                if in_line {
+                    match newline.next() {
+                        Some(val) => {
+                            i = val + 1;
+                            in_line = false;
+                        }
+                        None => i = length,
+                    };
+                }
+                else if (buffer[i] == delim) & !delim_ws {
                     state = match state {
                         ParseState::CmtNwLine => {
                             field_counter = 1;
@@ -264,11 +393,7 @@ pub fn count_num_fields<R: BufRead>(reader:&mut R, cmt: u8, delim: u8, delim_ws:
                 } 
                 else if (buffer[i] == b'\n') | (buffer[i] == b'\r') | (buffer[i] == cmt) {
                     if field_counter == 0 {
-                        let val = newline.next();
-                        i = match val {
-                            Some(val) => val + 1,
-                            None => length + 1,
-                        };
+                        in_line = true;
                     }
                     else {
                         if let ParseState::Delim = state { field_counter -= 1 };
@@ -294,15 +419,187 @@ pub fn count_num_fields<R: BufRead>(reader:&mut R, cmt: u8, delim: u8, delim_ws:
                 }
             }
             //Pass off our length to set our length outside of this block of code
-            i - 1
+            length
         };
-        //We now need to consume everything upto "length" in our buffer, so it's marked off as no longer being needed
+        //We now need to consume everything in our buffer, so it's marked off as no longer being needed
         reader.consume(length);
-        //If we've skipped over the desired number of lines we can exit the loop.
-        if length < BUF_SIZE {
+        //If our buffer came back empty we've reached the end of our file and can now exit.
+        if length == 0 {
             break;
         }
     }
 
+    //Our only line of data didn't end with a newline character
+    if let ParseState::Delim = state {
+        field_counter = field_counter.saturating_sub(1);
+    }
+
     field_counter
-}
\ No newline at end of file
+}
+
+///Reads in a single line along with its newline characters, where just like in the parser a line can end with a '\n', a '\r', or a "\r\n".
+///It returns the number of bytes that were read in, which is 0 once we're out of data.
+pub(crate) fn read_line<R: BufRead>(reader: &mut R, line: &mut Vec<u8>) -> std::io::Result<usize> {
+    let mut length = 0;
+    //Whether or not our line ended with a '\r', in which case we still need to check for a '\n' right after it
+    let mut after_cr = false;
+    loop {
+        let (done, used) = {
+            let buffer = match reader.fill_buf() {
+                Ok(buffer) => buffer,
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+            if after_cr {
+                let used = if buffer.first() == Some(&b'\n') { 1 } else { 0 };
+                line.extend_from_slice(&buffer[..used]);
+                (true, used)
+            } else {
+                match memchr::memchr2(b'\n', b'\r', buffer) {
+                    Some(val) if buffer[val] == b'\r' && val + 1 == buffer.len() => {
+                        line.extend_from_slice(buffer);
+                        after_cr = true;
+                        (false, buffer.len())
+                    }
+                    Some(val) => {
+                        let end = if buffer[val] == b'\r' && buffer[val + 1] == b'\n' { val + 2 } else { val + 1 };
+                        line.extend_from_slice(&buffer[..end]);
+                        (true, end)
+                    }
+                    None => {
+                        line.extend_from_slice(buffer);
+                        (buffer.is_empty(), buffer.len())
+                    }
+                }
+            }
+        };
+        reader.consume(used);
+        length += used;
+        if done {
+            return Ok(length);
+        }
+    }
+}
+
+///Whether or not a line would be read in as data, which means its first non-whitespace character
+///is not a newline or comment character.
+fn is_data_line(line: &[u8], cmt: u8) -> bool {
+    match line.iter().find(|&&x| (x != b' ') & (x != b'\t')) {
+        Some(&x) => (x != b'\n') & (x != b'\r') & (x != cmt),
+        None => false,
+    }
+}
+
+///Finds where the footer starts in data that's already in memory by working backwards from the end of it
+///until num_footer lines of data have been seen. It returns the data without the footer along with the number
+///of data lines that were found for the footer, which is only less than num_footer if there weren't enough lines of data.
+#[cfg(feature = "parallel")]
+pub(crate) fn split_footer(data: &[u8], cmt: u8, num_footer: usize) -> (&[u8], usize) {
+    let mut end = data.len();
+    let mut num_held_lines = 0;
+    while (num_held_lines < num_footer) & (end > 0) {
+        //Our line runs up to end, which includes its newline character if it has one.
+        //So, the line before it ends at the newline found before that.
+        let start = match memchr::memrchr2(b'\n', b'\r', &data[..end - 1]) {
+            Some(val) => val + 1,
+            None => 0,
+        };
+        if is_data_line(&data[start..end], cmt) {
+            num_held_lines += 1;
+        }
+        end = start;
+    }
+    (&data[..end], num_held_lines)
+}
+
+///FooterReader wraps another reader and holds back the last num_footer lines of data from it.
+///Since we can't look ahead to find where the footer starts without reading the data twice, every line goes into a
+///ring buffer first. A line is only handed on once num_footer more lines of data have been read in after it. So, when
+///we hit the end of the data the ring buffer holds exactly the footer, and those lines are never seen by the parser.
+///Commented lines are passed along in order with the data but don't count towards the footer.
+pub(crate) struct FooterReader<R: BufRead> {
+    reader: R,
+    cmt: u8,
+    num_footer: usize,
+    //The lines we're currently holding back along with whether or not they're data lines
+    ring: VecDeque<(Vec<u8>, bool)>,
+    //The number of data lines within our ring buffer
+    num_data_lines: usize,
+    //Old lines that we can reuse so we're not allocating for every line read
+    spare: Vec<Vec<u8>>,
+    buffer: Vec<u8>,
+    pos: usize,
+    eof: bool,
+}
+
+impl<R: BufRead> FooterReader<R> {
+    pub(crate) fn new(reader: R, cmt: u8, num_footer: usize) -> FooterReader<R> {
+        FooterReader {
+            reader,
+            cmt,
+            num_footer,
+            ring: VecDeque::with_capacity(num_footer + 1),
+            num_data_lines: 0,
+            spare: Vec::new(),
+            buffer: Vec::with_capacity(BUF_SIZE),
+            pos: 0,
+            eof: false,
+        }
+    }
+
+    ///The number of data lines that are being held back. Once all of the data has been read
+    ///this will only be less than num_footer if there weren't enough lines of data.
+    pub(crate) fn num_held_lines(&self) -> usize {
+        self.num_data_lines
+    }
+}
+
+impl<R: BufRead> Read for FooterReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let length = {
+            let data = self.fill_buf()?;
+            let length = data.len().min(buf.len());
+            buf[..length].copy_from_slice(&data[..length]);
+            length
+        };
+        self.consume(length);
+        Ok(length)
+    }
+}
+
+impl<R: BufRead> BufRead for FooterReader<R> {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        if (self.pos >= self.buffer.len()) & !self.eof {
+            self.buffer.clear();
+            self.pos = 0;
+            //We fill our buffer with whatever lines are no longer able to be a part of the footer
+            while (self.buffer.len() < BUF_SIZE) & !self.eof {
+                let mut line = self.spare.pop().unwrap_or_default();
+                line.clear();
+                if read_line(&mut self.reader, &mut line)? == 0 {
+                    self.eof = true;
+                    break;
+                }
+                let is_data = is_data_line(&line, self.cmt);
+                if is_data {
+                    self.num_data_lines += 1;
+                }
+                self.ring.push_back((line, is_data));
+                while self.num_data_lines > self.num_footer {
+                    if let Some((line, is_data)) = self.ring.pop_front() {
+                        if is_data {
+                            self.num_data_lines -= 1;
+                        }
+                        self.buffer.extend_from_slice(&line);
+                        self.spare.push(line);
+                    }
+                }
+            }
+        }
+        Ok(&self.buffer[self.pos..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.buffer.len());
+    }
+}
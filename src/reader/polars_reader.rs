@@ -0,0 +1,151 @@
+// This file is a part of the Rust Data Reader Library
+// Copyright 2018 Robert Carson
+//
+//    Licensed under the Apache License, Version 2.0 (the "License");
+//    you may not use this file except in compliance with the License.
+//    You may obtain a copy of the License at
+//
+//        http://www.apache.org/licenses/LICENSE-2.0
+//
+//    Unless required by applicable law or agreed to in writing, software
+//    distributed under the License is distributed on an "AS IS" BASIS,
+//    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//    See the License for the specific language governing permissions and
+//    limitations under the License.
+use super::*;
+
+use polars::prelude::{Column as PolarsColumn, DataFrame, NamedFrom, Series};
+
+///Converts each field of the column at icol, where empty fields become None. Our fields have already been read in by the time
+///they're converted, so the row and column of a field that fails to convert are filled in but its line and byte offset are left as 0.
+fn parse_column<T, P>(results: &RawReaderResultsCols, icol: usize) -> Result<Vec<Option<T>>, ReaderError>
+where
+    OptionParse<P>: ParseField<Option<T>>,
+{
+    results
+        .get_col_fields(icol)
+        .enumerate()
+        .map(|(irow, field)| {
+            <OptionParse<P> as ParseField<Option<T>>>::parse_field(field).map_err(|mut err| {
+                err.set_field_location(irow, icol, 0, 0);
+                err
+            })
+        })
+        .collect()
+}
+
+///Creates the Series for the column at icol, where its fields are converted to dtype.
+fn column_series(results: &RawReaderResultsCols, icol: usize, dtype: DataType, name: &str) -> Result<Series, ReaderError> {
+    let name = name.into();
+    let series = match dtype {
+        DataType::Bool => Series::new(name, parse_column::<bool, FromStrParse>(results, icol)?),
+        DataType::Char => {
+            let chars = parse_column::<char, FromStrParse>(results, icol)?;
+            Series::new(name, chars.into_iter().map(|x| x.map(String::from)).collect::<Vec<_>>())
+        }
+        DataType::I8 => Series::new(name, parse_column::<i8, LexicalParse>(results, icol)?),
+        DataType::I16 => Series::new(name, parse_column::<i16, LexicalParse>(results, icol)?),
+        DataType::I32 => Series::new(name, parse_column::<i32, LexicalParse>(results, icol)?),
+        DataType::I64 => Series::new(name, parse_column::<i64, LexicalParse>(results, icol)?),
+        DataType::U8 => Series::new(name, parse_column::<u8, LexicalParse>(results, icol)?),
+        DataType::U16 => Series::new(name, parse_column::<u16, LexicalParse>(results, icol)?),
+        DataType::U32 => Series::new(name, parse_column::<u32, LexicalParse>(results, icol)?),
+        DataType::U64 => Series::new(name, parse_column::<u64, LexicalParse>(results, icol)?),
+        DataType::F32 => Series::new(name, parse_column::<f32, LexicalParse>(results, icol)?),
+        DataType::F64 => Series::new(name, parse_column::<f64, LexicalParse>(results, icol)?),
+        DataType::String => Series::new(name, parse_column::<String, FromStrParse>(results, icol)?),
+    };
+    Ok(series)
+}
+
+impl RawReaderResultsCols {
+    ///Converts our results into a polars DataFrame with a Series for each of our columns, where the fields of a column are
+    ///converted to its type in dtypes. If dtypes isn't given the types are found using infer_schema. Empty fields, such as missing
+    ///fields without a fill value, become nulls. The names of our columns are used if they were read in, and otherwise each column
+    ///is called column_ followed by its 0 indexed position. It fails if dtypes doesn't have a type for every column or a field
+    ///can't be converted to its column's type.
+    pub fn into_dataframe(self, dtypes: Option<&[DataType]>) -> Result<DataFrame, ReaderError> {
+        let dtypes = match dtypes {
+            Some(dtypes) if dtypes.len() != self.num_fields => return Err(dtypes_error(dtypes.len(), self.num_fields)),
+            Some(dtypes) => dtypes.to_vec(),
+            None => self.infer_schema().dtypes(),
+        };
+        let columns = dtypes
+            .iter()
+            .enumerate()
+            .map(|(icol, &dtype)| {
+                let name = self.names.get(icol).cloned().unwrap_or_else(|| format!("column_{}", icol));
+                column_series(&self, icol, dtype, &name).map(PolarsColumn::from)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        DataFrame::new(columns).map_err(|err| ReaderError::InvalidParams {
+            field: "names",
+            reason: format!("couldn't be used for a DataFrame: {}", err),
+        })
+    }
+}
+
+//Our columns are read in as their raw bytes first, so they can be looked at to work out their types if they weren't given.
+macro_rules! load_dataframe {
+    ($parse:ident, $src:expr, $params:expr) => {{
+        let params = ReaderParams {
+            row_format: false,
+            ..$params.clone()
+        };
+        let results: RawReaderResultsCols = $parse($src, &params)?;
+        results.into_dataframe(params.dtypes.as_deref())
+    }};
+}
+
+///load_dataframe reads in a data file straight into a polars DataFrame, so data with whitespace delimiters, comments, or footers
+///can make it into polars. The type of each column is given by ReaderParams::dtypes in the same way as for load_txt_table, such as
+///from a Schema that was found using infer_schema. If dtypes isn't given then the types are worked out from the data that was read in
+///using the same rules as infer_schema. The columns are read in as their raw bytes before being converted to their types, and any
+///empty fields, such as missing fields without a fill value, become nulls. It can fail in a number of ways related to invalid parameters
+///or the data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
+///If a malformed field is seen the error contains the row and column of the field.
+///
+///Input -
+///
+/// f is simply the location of the file.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a DataFrame or an error.
+pub fn load_dataframe(f: &str, params: &ReaderParams) -> Result<DataFrame, ReaderError> {
+    load_dataframe!(parse_file, f, params)
+}
+
+///load_dataframe_reader is the same as load_dataframe except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a DataFrame or an error.
+pub fn load_dataframe_reader<R: BufRead>(reader: R, params: &ReaderParams) -> Result<DataFrame, ReaderError> {
+    load_dataframe!(parse_reader, reader, params)
+}
+
+///load_dataframe_bytes is the same as load_dataframe except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a DataFrame or an error.
+pub fn load_dataframe_bytes(bytes: &[u8], params: &ReaderParams) -> Result<DataFrame, ReaderError> {
+    load_dataframe!(parse_slice, bytes, params)
+}
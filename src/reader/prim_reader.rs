@@ -16,7 +16,7 @@ use super::*;
 
 ///load_txt_string reads in a data file that is made up of string type data. If this assumption is not made then the parser will fail
 ///during the conversion between strings to string. It can also fail in a number of other ways related to invalid parameters or the
-///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the Error type.
+///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
 ///One should therefore check to make sure no errors are obtained when examining the file. If a malformed field is seen the error
 ///does contain information about what line number of the data file has the malformed field.
 ///
@@ -29,13 +29,45 @@ use super::*;
 ///Output -
 ///
 /// A Result type that either contains a ReaderResults structure or an error.
-pub fn load_txt_string(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<String>>, Error> {
-    load_text!(f, params, String)
+pub fn load_txt_string(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<String>>, ReaderError> {
+    load_text!(@parse parse_file, f, params, String)
+}
+
+///load_reader_string is the same as load_txt_string except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_reader_string<R: BufRead>(reader: R, params: &ReaderParams) -> Result<Box<dyn ReaderResults<String>>, ReaderError> {
+    load_text!(@reader reader, params, String)
+}
+
+///load_bytes_string is the same as load_txt_string except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_bytes_string(bytes: &[u8], params: &ReaderParams) -> Result<Box<dyn ReaderResults<String>>, ReaderError> {
+    load_text!(@parse parse_slice, bytes, params, String)
 }
 
 ///load_txt_bool reads in a data file that is made up of bool type data. If this assumption is not made then the parser will fail
 ///during the conversion between strings to bool. It can also fail in a number of other ways related to invalid parameters or the
-///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the Error type.
+///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
 ///One should therefore check to make sure no errors are obtained when examining the file. If a malformed field is seen the error
 ///does contain information about what line number of the data file has the malformed field.
 ///Bool values must be written as true or false for Rust's native from_str to work. If you have it as a series of numbers then you'll want
@@ -50,13 +82,45 @@ pub fn load_txt_string(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderR
 ///Output -
 ///
 /// A Result type that either contains a ReaderResults structure or an error.
-pub fn load_txt_bool(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<bool>>, Error> {
-    load_text!(f, params, bool)
+pub fn load_txt_bool(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<bool>>, ReaderError> {
+    load_text!(@parse parse_file, f, params, bool)
+}
+
+///load_reader_bool is the same as load_txt_bool except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_reader_bool<R: BufRead>(reader: R, params: &ReaderParams) -> Result<Box<dyn ReaderResults<bool>>, ReaderError> {
+    load_text!(@reader reader, params, bool)
+}
+
+///load_bytes_bool is the same as load_txt_bool except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_bytes_bool(bytes: &[u8], params: &ReaderParams) -> Result<Box<dyn ReaderResults<bool>>, ReaderError> {
+    load_text!(@parse parse_slice, bytes, params, bool)
 }
 
 ///load_txt_char reads in a data file that is made up of char type data. If this assumption is not made then the parser will fail
 ///during the conversion between strings to char. It can also fail in a number of other ways related to invalid parameters or the
-///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the Error type.
+///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
 ///One should therefore check to make sure no errors are obtained when examining the file. If a malformed field is seen the error
 ///does contain information about what line number of the data file has the malformed field.
 ///This works as long as your data is simply single chars with a delimiter next to them. It will not return white spaces although since
@@ -71,6 +135,38 @@ pub fn load_txt_bool(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderRes
 ///Output -
 ///
 /// A Result type that either contains a ReaderResults structure or an error.
-pub fn load_txt_char(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<char>>, Error> {
-    load_text!(f, params, char)
+pub fn load_txt_char(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<char>>, ReaderError> {
+    load_text!(@parse parse_file, f, params, char)
+}
+
+///load_reader_char is the same as load_txt_char except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_reader_char<R: BufRead>(reader: R, params: &ReaderParams) -> Result<Box<dyn ReaderResults<char>>, ReaderError> {
+    load_text!(@reader reader, params, char)
+}
+
+///load_bytes_char is the same as load_txt_char except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_bytes_char(bytes: &[u8], params: &ReaderParams) -> Result<Box<dyn ReaderResults<char>>, ReaderError> {
+    load_text!(@parse parse_slice, bytes, params, char)
 }
@@ -0,0 +1,227 @@
+// This file is a part of the Rust Data Reader Library
+// Copyright 2018 Robert Carson
+//
+//    Licensed under the Apache License, Version 2.0 (the "License");
+//    you may not use this file except in compliance with the License.
+//    You may obtain a copy of the License at
+//
+//        http://www.apache.org/licenses/LICENSE-2.0
+//
+//    Unless required by applicable law or agreed to in writing, software
+//    distributed under the License is distributed on an "AS IS" BASIS,
+//    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//    See the License for the specific language governing permissions and
+//    limitations under the License.
+
+use std::error;
+use std::fmt;
+use std::io;
+use std::str::Utf8Error;
+use std::sync::Arc;
+
+///All of the errors that can be returned while reading in data. Each error that comes from the data itself tells us
+///the line number of the data file it was found on along with the byte offset into the data, so the offending data
+///can be easily tracked down. Line numbers are 1 based and count every line in the data including any header, comment, or blank lines,
+///where a line ends with a '\n', a '\r', or a "\r\n".
+///Byte offsets are 0 based and are taken from the start of the data. If the data was compressed they refer to the decompressed data.
+///
+/// Io - an error from reading in the data. It's wrapped in an Arc, so that our errors can be cloned.
+///
+/// InvalidParams - one of the fields in ReaderParams was not valid for the data. field is the name of the ReaderParams field.
+///
+/// NoData - the data didn't have any readable lines in it, since it was empty or only had blank and commented lines.
+///
+/// FieldCountMismatch - a line of data had a different number of fields than the first line of data.
+///     offset is the byte offset of the start of the line.
+///
+/// TrailingDelimiter - a line of data ended with a delimiter instead of a field. offset is the byte offset of the start of the line.
+///
+/// UnterminatedQuote - a quoted field was never closed, so it ran on until the end of the data. line is the line that the field
+///     started on and offset is the byte offset of its opening quote.
+///
+/// Conversion - a field could not be converted to the type we're loading the data as. row is the 0 based row of the results
+///     that the field would have been saved to, column is the 0 based column of the data that the field lives in,
+///     offset is the byte offset of the start of the field, bytes are the raw bytes of the field, and reason is why the conversion failed.
+///
+/// Utf8 - a field that needed to be a string was not valid UTF-8. The other values are the same as in Conversion.
+///
+/// Deserialize - a row of our results could not be deserialized with serde. row is the 0 based row of the results, column is the
+///     0 based column of the results for errors that came from a single field, and reason is why it failed.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum ReaderError {
+    Io(Arc<io::Error>),
+    InvalidParams {
+        field: &'static str,
+        reason: String,
+    },
+    NoData,
+    FieldCountMismatch {
+        line: usize,
+        offset: usize,
+        expected: usize,
+        found: usize,
+    },
+    TrailingDelimiter {
+        line: usize,
+        offset: usize,
+    },
+    UnterminatedQuote {
+        line: usize,
+        offset: usize,
+    },
+    Conversion {
+        row: usize,
+        column: usize,
+        line: usize,
+        offset: usize,
+        bytes: Vec<u8>,
+        reason: String,
+    },
+    Utf8 {
+        row: usize,
+        column: usize,
+        line: usize,
+        offset: usize,
+        bytes: Vec<u8>,
+        error: Utf8Error,
+    },
+    Deserialize {
+        row: usize,
+        column: Option<usize>,
+        reason: String,
+    },
+}
+
+impl ReaderError {
+    ///A field couldn't be converted to our type. The results only know what the field was and why it failed to convert,
+    ///so where the field lives in the data is filled in by the parser once the error makes it back up to it.
+    pub fn conversion(bytes: &[u8], reason: String) -> ReaderError {
+        ReaderError::Conversion {
+            row: 0,
+            column: 0,
+            line: 0,
+            offset: 0,
+            bytes: bytes.to_vec(),
+            reason,
+        }
+    }
+
+    ///A field wasn't valid UTF-8. Just like with conversion, where the field lives in the data is filled in by the parser.
+    pub fn utf8(bytes: &[u8], error: Utf8Error) -> ReaderError {
+        ReaderError::Utf8 {
+            row: 0,
+            column: 0,
+            line: 0,
+            offset: 0,
+            bytes: bytes.to_vec(),
+            error,
+        }
+    }
+
+    ///Whether or not this error came from a single line of our data, which means that the line could be skipped over
+    ///and we could keep on reading in the rest of the data.
+    pub fn is_row_error(&self) -> bool {
+        matches!(
+            self,
+            ReaderError::FieldCountMismatch { .. }
+                | ReaderError::TrailingDelimiter { .. }
+                | ReaderError::Conversion { .. }
+                | ReaderError::Utf8 { .. }
+        )
+    }
+
+    ///Fills in where a field that failed to convert lives in our data. Other errors are left alone.
+    pub(crate) fn set_field_location(&mut self, row_val: usize, column_val: usize, line_val: usize, offset_val: usize) {
+        match self {
+            ReaderError::Conversion { row, column, line, offset, .. } | ReaderError::Utf8 { row, column, line, offset, .. } => {
+                *row = row_val;
+                *column = column_val;
+                *line = line_val;
+                *offset = offset_val;
+            }
+            _ => {}
+        }
+    }
+
+    ///Any of our data chunks that were parsed separately only know about their own rows, so the rows from the
+    ///chunks before them need to be added on to the row of a field that failed to convert.
+    pub(crate) fn offset_row(&mut self, num_rows: usize) {
+        match self {
+            ReaderError::Conversion { row, .. } | ReaderError::Utf8 { row, .. } => *row += num_rows,
+            _ => {}
+        }
+    }
+}
+
+impl fmt::Display for ReaderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReaderError::Io(err) => write!(f, "Failed to read the data: {}", err),
+            ReaderError::InvalidParams { field, reason } => write!(f, "Input for {} {}", field, reason),
+            ReaderError::NoData => write!(f, "The data doesn't have any readable lines in it, since it's empty or only has blank and commented lines"),
+            ReaderError::FieldCountMismatch { line, offset, expected, found } => write!(
+                f,
+                "Number of fields,{}, provided at line {} (byte offset {}) is different than the initial field number of {}",
+                found, line, offset, expected
+            ),
+            ReaderError::TrailingDelimiter { line, offset } => write!(
+                f,
+                "Number of fields provided at line {} (byte offset {}) ends with a delimiter instead of a field or white space",
+                line, offset
+            ),
+            ReaderError::UnterminatedQuote { line, offset } => write!(
+                f,
+                "Quoted field starting at line {} (byte offset {}) is never closed before the end of the data",
+                line, offset
+            ),
+            ReaderError::Conversion { row, column, line, offset, bytes, reason } => write!(
+                f,
+                "Field {:?} in column {} of row {} provided at line {} (byte offset {}) could not be converted: {}",
+                String::from_utf8_lossy(bytes), column, row, line, offset, reason
+            ),
+            ReaderError::Utf8 { row, column, line, offset, bytes, error } => write!(
+                f,
+                "Field {:?} in column {} of row {} provided at line {} (byte offset {}) is not valid UTF-8: {}",
+                String::from_utf8_lossy(bytes), column, row, line, offset, error
+            ),
+            ReaderError::Deserialize { row, column: Some(column), reason } => {
+                write!(f, "Field in column {} of row {} could not be deserialized: {}", column, row, reason)
+            }
+            ReaderError::Deserialize { row, column: None, reason } => write!(f, "Row {} could not be deserialized: {}", row, reason),
+        }
+    }
+}
+
+impl error::Error for ReaderError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ReaderError::Io(err) => Some(err.as_ref()),
+            ReaderError::Utf8 { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ReaderError {
+    fn from(err: io::Error) -> ReaderError {
+        ReaderError::Io(Arc::new(err))
+    }
+}
+
+///A line of data that was skipped over when ReaderParams::row_errors is set to RowErrors::SkipAndReport.
+///
+/// line - the line number of the data file that the line was on
+///
+/// offset - the byte offset of the start of the line
+///
+/// bytes - the raw bytes of the line not including its newline character
+///
+/// reason - the error that caused the line to be skipped
+#[derive(Debug, Clone)]
+pub struct RejectedLine {
+    pub line: usize,
+    pub offset: usize,
+    pub bytes: Vec<u8>,
+    pub reason: ReaderError,
+}
@@ -13,38 +13,55 @@
 //    See the License for the specific language governing permissions and
 //    limitations under the License.
 
+use lexical::FromLexical;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str;
 use std::str::FromStr;
 use std::vec::*;
 
+use super::reader_error::{ReaderError, RejectedLine};
+use super::ReaderParams;
+
 ///A structure that contains all of the results in row major order. It tells us the number of fields we had
 ///along with the number of lines that we read. Finally, the results are stored in a single Vec of
-///type T. Type T is what type one called load_txt_* for.
+///type T. Type T is what type one called load_txt_* for. Any lines that were skipped over with
+///RowErrors::SkipAndReport are stored in rejected. If the names of the columns were read in they're stored in names.
+///The row and column of any fields that were missing and filled in are stored in filled.
 #[derive(Debug, Clone)]
 pub struct ReaderResultsRow<T> 
 where 
-    T: FromStr + Clone,
+    T: Clone,
 {
     pub num_fields: usize,
     pub num_lines: usize,
     pub results: Vec<T>,
+    pub rejected: Vec<RejectedLine>,
+    pub names: Vec<String>,
+    pub filled: Vec<(usize, usize)>,
 }
 
 ///A structure that contains all of the results in column major order. It tells us the number of fields we had
 ///along with the number of lines that we read. Finally, the results are stored in a single Vec of
-///type T. Type T is what type one called load_txt_* for.
+///type T. Type T is what type one called load_txt_* for. Any lines that were skipped over with
+///RowErrors::SkipAndReport are stored in rejected. If the names of the columns were read in they're stored in names.
+///The row and column of any fields that were missing and filled in are stored in filled.
 #[derive(Debug, Clone)]
 pub struct ReaderResultsCol<T>
 where 
-    T: FromStr + Clone,
+    T: Clone,
 {
     pub num_fields: usize,
     pub num_lines: usize,
     pub results: Vec<T>,
+    pub rejected: Vec<RejectedLine>,
+    pub names: Vec<String>,
+    pub filled: Vec<(usize, usize)>,
 }
 
 pub trait ReaderResults<T> 
 where 
-    T: FromStr + Clone,
+    T: Clone,
 {
     /// Return the number of fields
     fn get_num_fields(&self) -> usize;
@@ -71,12 +88,22 @@ where
     fn get_col(&self, col_index: usize) -> Vec<T>;
     /// Returns a copy of a desired columns
     fn get_cols(&self, col_indices: Vec<usize>) -> Vec<Vec<T>>;
+    /// Returns the lines that were skipped over and why
+    fn get_rejected(&self) -> &Vec<RejectedLine>;
+    /// Returns the names of the columns that were read in
+    fn get_names(&self) -> &Vec<String>;
+    /// Returns the row and column of the fields that were missing and filled in
+    fn get_filled(&self) -> &Vec<(usize, usize)>;
+    /// Returns a copy of the column with the given name if there is one
+    fn get_col_by_name(&self, name: &str) -> Option<Vec<T>> {
+        self.get_names().iter().position(|x| x == name).map(|index| self.get_col(index))
+    }
 
 }
 
 impl<T> ReaderResults<T> for ReaderResultsRow<T> 
 where 
-    T: FromStr + Clone,
+    T: Clone,
 {
     /// Return the number of fields
     fn get_num_fields(&self) -> usize {
@@ -172,11 +199,26 @@ where
 
         out
     }
+    ///Returns the lines that were skipped over when RowErrors::SkipAndReport was used
+    ///along with the error that caused each of them to be skipped.
+    fn get_rejected(&self) -> &Vec<RejectedLine> {
+        &self.rejected
+    }
+    ///Returns the names of the columns that were read in when ReaderParams::names was used.
+    ///If it wasn't used this is empty.
+    fn get_names(&self) -> &Vec<String> {
+        &self.names
+    }
+    ///Returns the row and column of every field that was missing and filled in when ReaderParams::missing_values was used.
+    ///They're in the order that they were read in.
+    fn get_filled(&self) -> &Vec<(usize, usize)> {
+        &self.filled
+    }
 }
 
 impl<T> ReaderResults<T> for ReaderResultsCol<T> 
 where 
-    T: FromStr + Clone,
+    T: Clone,
 {
     /// Return the number of fields
     fn get_num_fields(&self) -> usize {
@@ -272,28 +314,49 @@ where
 
         out
     }
+    ///Returns the lines that were skipped over when RowErrors::SkipAndReport was used
+    ///along with the error that caused each of them to be skipped.
+    fn get_rejected(&self) -> &Vec<RejectedLine> {
+        &self.rejected
+    }
+    ///Returns the names of the columns that were read in when ReaderParams::names was used.
+    ///If it wasn't used this is empty.
+    fn get_names(&self) -> &Vec<String> {
+        &self.names
+    }
+    ///Returns the row and column of every field that was missing and filled in when ReaderParams::missing_values was used.
+    ///They're in the order that they were read in.
+    fn get_filled(&self) -> &Vec<(usize, usize)> {
+        &self.filled
+    }
 }
 
 ///A structure that contains all of the raw results. It tells us the number of fields we had
 ///along with the number of lines that we read. Results contains all of the data that was read in
 ///from the file in its raw u8 format. The index field contains the starting index for each field
-///that was read in.
+///that was read in. Any lines that were skipped over with RowErrors::SkipAndReport are stored in rejected.
 pub struct RawReaderResultsRows {
     pub num_fields: usize,
     pub num_lines: usize,
     pub results: Vec<u8>,
     pub index: Vec<usize>,
+    pub rejected: Vec<RejectedLine>,
+    pub names: Vec<String>,
+    pub filled: Vec<(usize, usize)>,
 }
 
 ///A structure that contains all of the raw results. It tells us the number of fields we had
 ///along with the number of lines that we read. Results contains all of the data that was read in
 ///from the file in its raw u8 format. The index field contains the starting index for each field
-///that was read in.
+///that was read in. Any lines that were skipped over with RowErrors::SkipAndReport are stored in rejected.
 pub struct RawReaderResultsCols {
     pub num_fields: usize,
     pub num_lines: usize,
     pub results: Vec<Vec<u8>>,
     pub index: Vec<Vec<usize>>,
+    pub rejected: Vec<RejectedLine>,
+    pub names: Vec<String>,
+    pub filled: Vec<(usize, usize)>,
 }
 
 pub trait RawReaderParse {
@@ -302,7 +365,30 @@ pub trait RawReaderParse {
     fn incr_num_lines(&mut self);
     fn set_num_lines(&mut self, num_lines: usize);
     fn set_results(&mut self, value: u8, field: usize);
-    fn set_index(&mut self, field: usize);
+    /// Called once the parser reaches the end of a field. If the results convert the field to another type
+    /// and that fails, a ReaderError::Conversion or ReaderError::Utf8 error is returned with the bytes of the field.
+    /// The parser fills in where the field lives in the data.
+    fn set_index(&mut self, field: usize) -> Result<(), ReaderError>;
+    /// The parser only finds out how many fields the data has as it reads the first line.
+    /// Each time it comes across a new field on that line this is called with the 1-based field number,
+    /// so the results can grow to hold it before any values are saved off for that field.
+    fn set_num_fields(&mut self, _field: usize) {}
+    /// The current line of data couldn't be read in and is being skipped over, so anything that was saved off
+    /// from it needs to be removed. Afterwards, the results should only hold the lines that have been completed
+    /// along with num_fields fields, which is 0 if the number of fields isn't known yet.
+    fn rollback_line(&mut self, num_fields: usize);
+    /// Saves off a line of data that was skipped over when RowErrors::SkipAndReport is used.
+    fn add_rejected(&mut self, rejected: RejectedLine);
+    /// Called once right after the results are created, so that they can set themselves up using our parameters
+    /// such as ReaderParams::dtypes.
+    fn set_params(&mut self, _params: &ReaderParams) {}
+    /// Saves off the names of the columns that were read in when ReaderParams::names is used.
+    fn set_names(&mut self, names: Vec<String>);
+    /// Returns the bytes of the current field that have been saved off so far for the 1-based field.
+    fn get_field(&self, field: usize) -> &[u8];
+    /// The current field for the 1-based field was missing, so its bytes are replaced with fill.
+    /// The row and column of the field should be saved off as well.
+    fn fill_field(&mut self, field: usize, fill: &[u8]);
 }
 
 impl RawReaderParse for RawReaderResultsRows {
@@ -316,6 +402,9 @@ impl RawReaderParse for RawReaderResultsRows {
             num_lines: 0,
             results: Vec::<u8>::new(),
             index: Vec::<usize>::new(), 
+            rejected: Vec::<RejectedLine>::new(),
+            names: Vec::<String>::new(),
+            filled: Vec::<(usize, usize)>::new(),
         };
         rr.results.reserve(num_lines * field);
         rr.index.reserve(num_lines);
@@ -344,8 +433,53 @@ impl RawReaderParse for RawReaderResultsRows {
     }
 
     #[inline(always)]
-    fn set_index(&mut self, _field: usize) {
+    fn set_index(&mut self, _field: usize) -> Result<(), ReaderError> {
         self.index.push(self.results.len());
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn set_num_fields(&mut self, field: usize) {
+        if field > self.num_fields {
+            self.num_fields = field;
+        }
+    }
+
+    fn rollback_line(&mut self, num_fields: usize) {
+        self.num_fields = num_fields;
+        self.index.truncate(self.num_lines * self.num_fields);
+        self.results.truncate(self.index.last().copied().unwrap_or(0));
+        rollback_filled(&mut self.filled, self.num_lines);
+    }
+
+    fn add_rejected(&mut self, rejected: RejectedLine) {
+        self.rejected.push(rejected);
+    }
+
+    fn set_names(&mut self, names: Vec<String>) {
+        self.names = names;
+    }
+
+    fn get_field(&self, _field: usize) -> &[u8] {
+        &self.results[self.index.last().copied().unwrap_or(0)..]
+    }
+
+    fn fill_field(&mut self, _field: usize, fill: &[u8]) {
+        self.results.truncate(self.index.last().copied().unwrap_or(0));
+        self.results.extend_from_slice(fill);
+        self.filled.push((self.num_lines, self.index.len() - self.num_lines * self.num_fields));
+    }
+}
+
+impl RawReaderResultsCols {
+    ///Returns the raw bytes of each field in the column at col_index in the order that they were read in.
+    pub fn get_col_fields(&self, col_index: usize) -> impl Iterator<Item = &[u8]> + '_ {
+        let results = &self.results[col_index];
+        self.index[col_index].iter().scan(0, move |start, &end| {
+            let field = &results[*start..end];
+            *start = end;
+            Some(field)
+        })
     }
 }
 
@@ -359,6 +493,9 @@ impl RawReaderParse for RawReaderResultsCols {
             num_lines: 0,
             results: Vec::<Vec<u8>>::new(),
             index: Vec::<Vec<usize>>::new(), 
+            rejected: Vec::<RejectedLine>::new(),
+            names: Vec::<String>::new(),
+            filled: Vec::<(usize, usize)>::new(),
         };
 
         for icol in 0..field {
@@ -393,8 +530,602 @@ impl RawReaderParse for RawReaderResultsCols {
     }
 
     #[inline(always)]
-    fn set_index(&mut self, field: usize) {
+    fn set_index(&mut self, field: usize) -> Result<(), ReaderError> {
         assert!(field - 1 < self.num_fields);
         self.index[field - 1].push(self.results[field - 1].len());
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn set_num_fields(&mut self, field: usize) {
+        while self.num_fields < field {
+            self.results.push(Vec::<u8>::new());
+            self.index.push(Vec::<usize>::new());
+            self.num_fields += 1;
+        }
+    }
+
+    fn rollback_line(&mut self, num_fields: usize) {
+        self.num_fields = num_fields;
+        self.results.truncate(self.num_fields);
+        self.index.truncate(self.num_fields);
+        for (results, index) in self.results.iter_mut().zip(self.index.iter_mut()) {
+            index.truncate(self.num_lines);
+            results.truncate(index.last().copied().unwrap_or(0));
+        }
+        rollback_filled(&mut self.filled, self.num_lines);
+    }
+
+    fn add_rejected(&mut self, rejected: RejectedLine) {
+        self.rejected.push(rejected);
+    }
+
+    fn set_names(&mut self, names: Vec<String>) {
+        self.names = names;
+    }
+
+    fn get_field(&self, field: usize) -> &[u8] {
+        &self.results[field - 1][self.index[field - 1].last().copied().unwrap_or(0)..]
+    }
+
+    fn fill_field(&mut self, field: usize, fill: &[u8]) {
+        let start = self.index[field - 1].last().copied().unwrap_or(0);
+        self.results[field - 1].truncate(start);
+        self.results[field - 1].extend_from_slice(fill);
+        self.filled.push((self.num_lines, field - 1));
+    }
+}
+
+///Allows the raw results that were parsed from separate chunks of the same data to be stitched back together.
+///The chunks need to be appended in the same order as they appeared within the data.
+pub trait RawReaderAppend {
+    fn append(&mut self, other: Self);
+}
+
+impl RawReaderAppend for RawReaderResultsRows {
+    fn append(&mut self, other: Self) {
+        //Our index values need to now point to where the other results are going to live in our results
+        let offset = self.results.len();
+        self.index.extend(other.index.iter().map(|&x| x + offset));
+        self.results.extend(other.results);
+        //The rows of any fields that failed to convert need to take into account our rows
+        let num_lines = self.num_lines;
+        self.rejected.extend(other.rejected.into_iter().map(|mut rejected| {
+            rejected.reason.offset_row(num_lines);
+            rejected
+        }));
+        self.filled.extend(other.filled.iter().map(|&(row, col)| (row + num_lines, col)));
+        self.num_lines += other.num_lines;
+        if other.num_fields > self.num_fields {
+            self.num_fields = other.num_fields;
+        }
+    }
+}
+
+impl RawReaderAppend for RawReaderResultsCols {
+    fn append(&mut self, other: Self) {
+        self.set_num_fields(other.num_fields);
+        for (icol, (results, index)) in other.results.into_iter().zip(other.index).enumerate() {
+            //Our index values need to now point to where the other results are going to live in our results
+            let offset = self.results[icol].len();
+            self.index[icol].extend(index.iter().map(|&x| x + offset));
+            self.results[icol].extend(results);
+        }
+        //The rows of any fields that failed to convert need to take into account our rows
+        let num_lines = self.num_lines;
+        self.rejected.extend(other.rejected.into_iter().map(|mut rejected| {
+            rejected.reason.offset_row(num_lines);
+            rejected
+        }));
+        self.filled.extend(other.filled.iter().map(|&(row, col)| (row + num_lines, col)));
+        self.num_lines += other.num_lines;
+    }
+}
+
+///Converts the raw bytes of a field into type T. If that fails either a ReaderError::Conversion or ReaderError::Utf8 error
+///should be returned using ReaderError::conversion or ReaderError::utf8. The parser fills in where the field lives in the data.
+pub trait ParseField<T> {
+    fn parse_field(bytes: &[u8]) -> Result<T, ReaderError>;
+}
+
+///Uses the lexical crate to convert fields, which is the fastest option for the primitive number types.
+pub struct LexicalParse;
+
+///Uses the FromStr trait to convert fields, so it works with any type that can be parsed from a string.
+pub struct FromStrParse;
+
+impl<T> ParseField<T> for LexicalParse
+where
+    T: FromLexical,
+{
+    #[inline(always)]
+    fn parse_field(bytes: &[u8]) -> Result<T, ReaderError> {
+        lexical::parse::<T, _>(bytes).map_err(|err| ReaderError::conversion(bytes, err.to_string()))
+    }
+}
+
+impl<T> ParseField<T> for FromStrParse
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    #[inline(always)]
+    fn parse_field(bytes: &[u8]) -> Result<T, ReaderError> {
+        let temp_str = str::from_utf8(bytes).map_err(|err| ReaderError::utf8(bytes, err))?;
+        T::from_str(temp_str).map_err(|err| ReaderError::conversion(bytes, err.to_string()))
+    }
+}
+
+///Wraps another way of converting fields, so that fields with nothing in them become None and every other field is converted
+///using P. Missing fields that don't have a fill value are left empty, so used along with ReaderParams::missing_values
+///they're read in as None.
+pub struct OptionParse<P = LexicalParse> {
+    parse: PhantomData<P>,
+}
+
+impl<T, P> ParseField<Option<T>> for OptionParse<P>
+where
+    P: ParseField<T>,
+{
+    #[inline(always)]
+    fn parse_field(bytes: &[u8]) -> Result<Option<T>, ReaderError> {
+        if bytes.is_empty() {
+            Ok(None)
+        } else {
+            P::parse_field(bytes).map(Some)
+        }
+    }
+}
+
+///A structure that contains all of the typed results in row major order. Unlike RawReaderResultsRows, each field is
+///converted to type T as soon as the parser reaches the end of it, so the raw bytes of the data are never saved off.
+///Type P determines how the fields are converted. The field value holds the bytes of the field that is currently being parsed,
+///since a field can be split across buffers.
+pub struct TypedReaderResultsRows<T, P = LexicalParse> {
+    pub num_fields: usize,
+    pub num_lines: usize,
+    pub results: Vec<T>,
+    pub rejected: Vec<RejectedLine>,
+    pub names: Vec<String>,
+    pub filled: Vec<(usize, usize)>,
+    field: Vec<u8>,
+    parse: PhantomData<P>,
+}
+
+///A structure that contains all of the typed results in column major order. Unlike RawReaderResultsCols, each field is
+///converted to type T as soon as the parser reaches the end of it, so the raw bytes of the data are never saved off.
+///Type P determines how the fields are converted. The field value holds the bytes of the field that is currently being parsed,
+///since a field can be split across buffers.
+pub struct TypedReaderResultsCols<T, P = LexicalParse> {
+    pub num_fields: usize,
+    pub num_lines: usize,
+    pub results: Vec<Vec<T>>,
+    pub rejected: Vec<RejectedLine>,
+    pub names: Vec<String>,
+    pub filled: Vec<(usize, usize)>,
+    field: Vec<u8>,
+    parse: PhantomData<P>,
+}
+
+impl<T, P> RawReaderParse for TypedReaderResultsRows<T, P>
+where
+    P: ParseField<T>,
+{
+
+    #[inline(always)]
+    fn new(field: usize, num_lines: usize) -> Self {
+        let mut rr = 
+        TypedReaderResultsRows {
+            num_fields: field,
+            num_lines: 0,
+            results: Vec::<T>::new(),
+            rejected: Vec::<RejectedLine>::new(),
+            names: Vec::<String>::new(),
+            filled: Vec::<(usize, usize)>::new(),
+            field: Vec::<u8>::new(),
+            parse: PhantomData,
+        };
+        rr.results.reserve(num_lines * field);
+
+        rr
+    }
+
+    #[inline(always)]
+    fn get_num_lines(&self) -> usize {
+        self.num_lines
+    }
+
+    #[inline(always)]
+    fn incr_num_lines(&mut self) {
+        self.num_lines += 1;
+    }
+
+    #[inline(always)]
+    fn set_num_lines(&mut self, num_lines: usize) {
+        self.num_lines = num_lines;
+    }
+
+    #[inline(always)]
+    fn set_results(&mut self, value: u8, _field: usize) {
+        self.field.push(value);
+    }
+
+    #[inline(always)]
+    fn set_index(&mut self, _field: usize) -> Result<(), ReaderError> {
+        //The field is cleared out even if it failed to convert, so we're ready for the next one.
+        let temp = P::parse_field(&self.field);
+        self.field.clear();
+        self.results.push(temp?);
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn set_num_fields(&mut self, field: usize) {
+        if field > self.num_fields {
+            self.num_fields = field;
+        }
+    }
+
+    fn rollback_line(&mut self, num_fields: usize) {
+        self.num_fields = num_fields;
+        self.results.truncate(self.num_lines * self.num_fields);
+        self.field.clear();
+        rollback_filled(&mut self.filled, self.num_lines);
+    }
+
+    fn add_rejected(&mut self, rejected: RejectedLine) {
+        self.rejected.push(rejected);
+    }
+
+    fn set_names(&mut self, names: Vec<String>) {
+        self.names = names;
+    }
+
+    fn get_field(&self, _field: usize) -> &[u8] {
+        &self.field
+    }
+
+    fn fill_field(&mut self, _field: usize, fill: &[u8]) {
+        self.field.clear();
+        self.field.extend_from_slice(fill);
+        self.filled.push((self.num_lines, self.results.len() - self.num_lines * self.num_fields));
+    }
+}
+
+impl<T, P> RawReaderParse for TypedReaderResultsCols<T, P>
+where
+    P: ParseField<T>,
+{
+
+    #[inline(always)]
+    fn new(field: usize, num_lines: usize) -> Self {
+        let mut rr = 
+        TypedReaderResultsCols {
+            num_fields: field,
+            num_lines: 0,
+            results: Vec::<Vec<T>>::new(),
+            rejected: Vec::<RejectedLine>::new(),
+            names: Vec::<String>::new(),
+            filled: Vec::<(usize, usize)>::new(),
+            field: Vec::<u8>::new(),
+            parse: PhantomData,
+        };
+
+        for _ in 0..field {
+            rr.results.push(Vec::<T>::with_capacity(num_lines));
+        }
+
+        rr
+    }
+
+    #[inline(always)]
+    fn get_num_lines(&self) -> usize {
+        self.num_lines
+    }
+
+    #[inline(always)]
+    fn incr_num_lines(&mut self) {
+        self.num_lines += 1;
+    }
+
+    #[inline(always)]
+    fn set_num_lines(&mut self, num_lines: usize) {
+        self.num_lines = num_lines;
+    }
+
+    #[inline(always)]
+    fn set_results(&mut self, value: u8, _field: usize) {
+        self.field.push(value);
+    }
+
+    #[inline(always)]
+    fn set_index(&mut self, field: usize) -> Result<(), ReaderError> {
+        assert!(field - 1 < self.num_fields);
+        //The field is cleared out even if it failed to convert, so we're ready for the next one.
+        let temp = P::parse_field(&self.field);
+        self.field.clear();
+        self.results[field - 1].push(temp?);
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn set_num_fields(&mut self, field: usize) {
+        while self.num_fields < field {
+            self.results.push(Vec::<T>::new());
+            self.num_fields += 1;
+        }
+    }
+
+    fn rollback_line(&mut self, num_fields: usize) {
+        self.num_fields = num_fields;
+        self.results.truncate(self.num_fields);
+        for results in self.results.iter_mut() {
+            results.truncate(self.num_lines);
+        }
+        self.field.clear();
+        rollback_filled(&mut self.filled, self.num_lines);
+    }
+
+    fn add_rejected(&mut self, rejected: RejectedLine) {
+        self.rejected.push(rejected);
+    }
+
+    fn set_names(&mut self, names: Vec<String>) {
+        self.names = names;
+    }
+
+    fn get_field(&self, _field: usize) -> &[u8] {
+        &self.field
+    }
+
+    fn fill_field(&mut self, field: usize, fill: &[u8]) {
+        self.field.clear();
+        self.field.extend_from_slice(fill);
+        self.filled.push((self.num_lines, field - 1));
+    }
+}
+
+impl<T, P> RawReaderAppend for TypedReaderResultsRows<T, P> {
+    fn append(&mut self, other: Self) {
+        self.results.extend(other.results);
+        //The rows of any fields that failed to convert need to take into account our rows
+        let num_lines = self.num_lines;
+        self.rejected.extend(other.rejected.into_iter().map(|mut rejected| {
+            rejected.reason.offset_row(num_lines);
+            rejected
+        }));
+        self.filled.extend(other.filled.iter().map(|&(row, col)| (row + num_lines, col)));
+        self.num_lines += other.num_lines;
+        if other.num_fields > self.num_fields {
+            self.num_fields = other.num_fields;
+        }
+    }
+}
+
+impl<T, P> RawReaderAppend for TypedReaderResultsCols<T, P> {
+    fn append(&mut self, other: Self) {
+        //We need enough columns to hold everything from the other results
+        while self.num_fields < other.num_fields {
+            self.results.push(Vec::<T>::new());
+            self.num_fields += 1;
+        }
+        for (icol, results) in other.results.into_iter().enumerate() {
+            self.results[icol].extend(results);
+        }
+        //The rows of any fields that failed to convert need to take into account our rows
+        let num_lines = self.num_lines;
+        self.rejected.extend(other.rejected.into_iter().map(|mut rejected| {
+            rejected.reason.offset_row(num_lines);
+            rejected
+        }));
+        self.filled.extend(other.filled.iter().map(|&(row, col)| (row + num_lines, col)));
+        self.num_lines += other.num_lines;
+    }
+}
+
+impl<T, P> From<TypedReaderResultsRows<T, P>> for ReaderResultsRow<T>
+where
+    T: Clone,
+{
+    fn from(typed_results: TypedReaderResultsRows<T, P>) -> Self {
+        ReaderResultsRow {
+            num_fields: typed_results.num_fields,
+            num_lines: typed_results.num_lines,
+            results: typed_results.results,
+            rejected: typed_results.rejected,
+            names: typed_results.names,
+            filled: typed_results.filled,
+        }
+    }
+}
+
+impl<T, P> From<TypedReaderResultsCols<T, P>> for ReaderResultsCol<T>
+where
+    T: Clone,
+{
+    fn from(typed_results: TypedReaderResultsCols<T, P>) -> Self {
+        //Each column was saved off separately, so they now need to be laid out one after another.
+        let mut results = Vec::<T>::with_capacity(typed_results.num_fields * typed_results.num_lines);
+        for col in typed_results.results {
+            results.extend(col);
+        }
+        ReaderResultsCol {
+            num_fields: typed_results.num_fields,
+            num_lines: typed_results.num_lines,
+            results,
+            rejected: typed_results.rejected,
+            names: typed_results.names,
+            filled: typed_results.filled,
+        }
     }
-}
\ No newline at end of file
+}
+
+///A structure that contains all of the typed results in column major order, where every column lives in a single Vec
+///so that it can become the Fortran ordered storage of an array without being copied. Column c lives at
+///c * stride..(c + 1) * stride of results, where stride is the number of lines that there's room for, which comes from the
+///number of lines that we're told about when the results are created. If there ends up being more lines than that, the columns
+///are grown and moved within results. Any room that wasn't used is packed away when they're turned into ReaderResultsCol.
+#[cfg(feature = "ndarray")]
+pub(crate) struct TypedReaderResultsColsPacked<T, P = LexicalParse> {
+    pub num_fields: usize,
+    pub num_lines: usize,
+    pub results: Vec<T>,
+    pub rejected: Vec<RejectedLine>,
+    pub names: Vec<String>,
+    pub filled: Vec<(usize, usize)>,
+    stride: usize,
+    field: Vec<u8>,
+    parse: PhantomData<P>,
+}
+
+#[cfg(feature = "ndarray")]
+impl<T: Clone, P> TypedReaderResultsColsPacked<T, P> {
+    ///Makes room for more lines in each of our columns. The columns are moved from the last one to the first one and
+    ///each of them from its last line to its first line, so nothing is written over before it's been moved.
+    fn grow(&mut self, fill: T) {
+        let old_stride = self.stride;
+        self.stride = (old_stride * 2).max(self.num_lines + 1).max(64);
+        let num_cols = self.results.len().checked_div(old_stride).unwrap_or(0);
+        self.results.resize(num_cols * self.stride, fill);
+        for icol in (1..num_cols).rev() {
+            for irow in (0..old_stride).rev() {
+                self.results.swap(icol * old_stride + irow, icol * self.stride + irow);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T, P> RawReaderParse for TypedReaderResultsColsPacked<T, P>
+where
+    T: Clone,
+    P: ParseField<T>,
+{
+
+    #[inline(always)]
+    fn new(field: usize, num_lines: usize) -> Self {
+        TypedReaderResultsColsPacked {
+            num_fields: field,
+            num_lines: 0,
+            results: Vec::<T>::with_capacity(num_lines * field),
+            rejected: Vec::<RejectedLine>::new(),
+            names: Vec::<String>::new(),
+            filled: Vec::<(usize, usize)>::new(),
+            stride: num_lines,
+            field: Vec::<u8>::new(),
+            parse: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    fn get_num_lines(&self) -> usize {
+        self.num_lines
+    }
+
+    #[inline(always)]
+    fn incr_num_lines(&mut self) {
+        self.num_lines += 1;
+    }
+
+    #[inline(always)]
+    fn set_num_lines(&mut self, num_lines: usize) {
+        self.num_lines = num_lines;
+    }
+
+    #[inline(always)]
+    fn set_results(&mut self, value: u8, _field: usize) {
+        self.field.push(value);
+    }
+
+    #[inline(always)]
+    fn set_index(&mut self, field: usize) -> Result<(), ReaderError> {
+        assert!(field - 1 < self.num_fields);
+        //The field is cleared out even if it failed to convert, so we're ready for the next one.
+        let temp = P::parse_field(&self.field);
+        self.field.clear();
+        let value = temp?;
+        if self.num_lines >= self.stride {
+            self.grow(value.clone());
+        }
+        //A column is given all of its room the first time that a value is saved off for it.
+        let index = (field - 1) * self.stride + self.num_lines;
+        if index < self.results.len() {
+            self.results[index] = value;
+        } else {
+            self.results.resize(field * self.stride, value);
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn set_num_fields(&mut self, field: usize) {
+        if field > self.num_fields {
+            self.num_fields = field;
+        }
+    }
+
+    fn rollback_line(&mut self, num_fields: usize) {
+        //Anything saved off from the current line is written over by the next line or packed away at the end.
+        self.num_fields = num_fields;
+        self.field.clear();
+        rollback_filled(&mut self.filled, self.num_lines);
+    }
+
+    fn add_rejected(&mut self, rejected: RejectedLine) {
+        self.rejected.push(rejected);
+    }
+
+    fn set_names(&mut self, names: Vec<String>) {
+        self.names = names;
+    }
+
+    fn get_field(&self, _field: usize) -> &[u8] {
+        &self.field
+    }
+
+    fn fill_field(&mut self, field: usize, fill: &[u8]) {
+        self.field.clear();
+        self.field.extend_from_slice(fill);
+        self.filled.push((self.num_lines, field - 1));
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T, P> From<TypedReaderResultsColsPacked<T, P>> for ReaderResultsCol<T>
+where
+    T: Clone,
+{
+    fn from(typed_results: TypedReaderResultsColsPacked<T, P>) -> Self {
+        let num_fields = typed_results.num_fields;
+        let num_lines = typed_results.num_lines;
+        let stride = typed_results.stride;
+        let mut results = typed_results.results;
+        //Our columns only need to be moved if there was room for more lines than were read in.
+        //Each value is moved towards the start of results, so nothing is written over before it's been moved.
+        if num_lines == 0 {
+            results.clear();
+        } else if stride != num_lines {
+            for icol in 1..num_fields {
+                for irow in 0..num_lines {
+                    results.swap(icol * num_lines + irow, icol * stride + irow);
+                }
+            }
+        }
+        results.truncate(num_fields * num_lines);
+        ReaderResultsCol {
+            num_fields,
+            num_lines,
+            results,
+            rejected: typed_results.rejected,
+            names: typed_results.names,
+            filled: typed_results.filled,
+        }
+    }
+}
+
+///Removes the fields that were filled in from any lines that were rolled back, which are the ones at the end.
+pub(crate) fn rollback_filled(filled: &mut Vec<(usize, usize)>, num_lines: usize) {
+    while filled.last().is_some_and(|x| x.0 >= num_lines) {
+        filled.pop();
+    }
+}
@@ -0,0 +1,273 @@
+// This file is a part of the Rust Data Reader Library
+// Copyright 2018 Robert Carson
+//
+//    Licensed under the Apache License, Version 2.0 (the "License");
+//    you may not use this file except in compliance with the License.
+//    You may obtain a copy of the License at
+//
+//        http://www.apache.org/licenses/LICENSE-2.0
+//
+//    Unless required by applicable law or agreed to in writing, software
+//    distributed under the License is distributed on an "AS IS" BASIS,
+//    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//    See the License for the specific language governing permissions and
+//    limitations under the License.
+use super::*;
+
+use std::mem;
+
+///Converts the bytes of a single field to a type, which is how each field of a struct that implements FromRow is read in.
+///The primitive number types are converted using the lexical crate, while bool, char, and String use the FromStr trait.
+///An Option<T> is None when the field is empty, such as a missing field without a fill value, and it's Some otherwise.
+///Any other type that a field of a struct should be read in as just needs to implement this trait.
+pub trait FromField: Sized {
+    fn from_field(bytes: &[u8]) -> Result<Self, ReaderError>;
+}
+
+macro_rules! from_field {
+    ($parse:ty, $($type:ty),*) => {
+        $(
+            impl FromField for $type {
+                #[inline(always)]
+                fn from_field(bytes: &[u8]) -> Result<Self, ReaderError> {
+                    <$parse as ParseField<$type>>::parse_field(bytes)
+                }
+            }
+        )*
+    };
+}
+
+from_field!(LexicalParse, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+from_field!(FromStrParse, bool, char, String);
+
+impl<T: FromField> FromField for Option<T> {
+    #[inline(always)]
+    fn from_field(bytes: &[u8]) -> Result<Self, ReaderError> {
+        if bytes.is_empty() {
+            Ok(None)
+        } else {
+            T::from_field(bytes).map(Some)
+        }
+    }
+}
+
+///A struct that each row of our data can be read into. It's normally implemented using #[derive(FromRow)], which is available
+///with the derive feature. Each field of the struct is read in from its own column of the data using the FromField trait.
+///
+///```ignore
+///#[derive(FromRow)]
+///struct Particle {
+///    id: u64,
+///    #[col(3)]
+///    mass: f64,
+///    #[col(name = "temp", default = 273.15)]
+///    temp: f64,
+///}
+///```
+///
+///A field can have a #[col(...)] attribute which contains any of the following:
+///
+/// index - the 0 indexed column that the field is read in from such as #[col(3)]. Fields without an index or name are read in from
+///     the column with the same index as the field's position in the struct.
+///
+/// name - the name of the column that the field is read in from such as #[col(name = "temp")]. The column names must be read in using
+///     ReaderParams::names for this to work.
+///
+/// default - the value a field takes when it's empty such as #[col(default = 0.0)]. If no value is given the type's Default is used.
+///     Empty fields are only seen when ReaderParams::quote or ReaderParams::missing_values are used.
+pub trait FromRow: Sized {
+    ///The fields of a row that's in the middle of being read in
+    type Row: Default;
+    ///The columns of our data that our struct is read in from, which are in the same order as the fields of our struct.
+    fn columns() -> Vec<Column>;
+    ///Converts the bytes of a field and saves it off as the field of our row at the 0 indexed position index.
+    fn set_field(row: &mut Self::Row, index: usize, bytes: &[u8]) -> Result<(), ReaderError>;
+    ///Creates our struct from a row once all of its fields have been set.
+    fn from_row(row: Self::Row) -> Self;
+}
+
+///A structure that contains all of the results when each row of our data is read into a struct that implements FromRow.
+///It tells us the number of fields we had along with the number of lines that we read. Each row is stored in records.
+///Any lines that were skipped over with RowErrors::SkipAndReport are stored in rejected. If the names of the columns were read in
+///they're stored in names. The row and column of any fields that were missing and filled in are stored in filled.
+///
+///It can be handed to parse_txt or any of the other parsers directly if the rejected lines are needed, but ReaderParams::usecols
+///needs to be set to T::columns() first. Otherwise, it's easiest to use load_txt_records.
+pub struct Records<T: FromRow> {
+    pub num_fields: usize,
+    pub num_lines: usize,
+    pub records: Vec<T>,
+    pub rejected: Vec<RejectedLine>,
+    pub names: Vec<String>,
+    pub filled: Vec<(usize, usize)>,
+    row: T::Row,
+    num_set: usize,
+    field: Vec<u8>,
+}
+
+impl<T: FromRow> RawReaderParse for Records<T> {
+    #[inline(always)]
+    fn new(field: usize, num_lines: usize) -> Self {
+        Records {
+            num_fields: field,
+            num_lines: 0,
+            records: Vec::<T>::with_capacity(num_lines),
+            rejected: Vec::<RejectedLine>::new(),
+            names: Vec::<String>::new(),
+            filled: Vec::<(usize, usize)>::new(),
+            row: T::Row::default(),
+            num_set: 0,
+            field: Vec::<u8>::new(),
+        }
+    }
+
+    #[inline(always)]
+    fn get_num_lines(&self) -> usize {
+        self.num_lines
+    }
+
+    #[inline(always)]
+    fn incr_num_lines(&mut self) {
+        self.num_lines += 1;
+    }
+
+    #[inline(always)]
+    fn set_num_lines(&mut self, num_lines: usize) {
+        self.num_lines = num_lines;
+    }
+
+    #[inline(always)]
+    fn set_results(&mut self, value: u8, _field: usize) {
+        self.field.push(value);
+    }
+
+    ///Our struct is created as soon as the last of its fields has been converted, so the fields don't need to be saved off anywhere else.
+    #[inline(always)]
+    fn set_index(&mut self, field: usize) -> Result<(), ReaderError> {
+        //The field is cleared out even if it failed to convert, so we're ready for the next one.
+        let temp = T::set_field(&mut self.row, field - 1, &self.field);
+        self.field.clear();
+        temp?;
+        self.num_set += 1;
+        if self.num_set == self.num_fields {
+            self.records.push(T::from_row(mem::take(&mut self.row)));
+            self.num_set = 0;
+        }
+        Ok(())
+    }
+
+    fn rollback_line(&mut self, _num_fields: usize) {
+        self.records.truncate(self.num_lines);
+        self.row = T::Row::default();
+        self.num_set = 0;
+        self.field.clear();
+        rollback_filled(&mut self.filled, self.num_lines);
+    }
+
+    fn add_rejected(&mut self, rejected: RejectedLine) {
+        self.rejected.push(rejected);
+    }
+
+    fn set_names(&mut self, names: Vec<String>) {
+        self.names = names;
+    }
+
+    fn get_field(&self, _field: usize) -> &[u8] {
+        &self.field
+    }
+
+    fn fill_field(&mut self, field: usize, fill: &[u8]) {
+        self.field.clear();
+        self.field.extend_from_slice(fill);
+        self.filled.push((self.num_lines, field - 1));
+    }
+}
+
+impl<T: FromRow> RawReaderAppend for Records<T> {
+    fn append(&mut self, other: Self) {
+        //The rows of any fields that failed to convert need to take into account our rows
+        let num_lines = self.num_lines;
+        self.records.extend(other.records);
+        self.rejected.extend(other.rejected.into_iter().map(|mut rejected| {
+            rejected.reason.offset_row(num_lines);
+            rejected
+        }));
+        self.filled.extend(other.filled.iter().map(|&(row, col)| (row + num_lines, col)));
+        self.num_lines += other.num_lines;
+    }
+}
+
+///Only the columns that our struct is read in from are used, so usecols is replaced with them.
+fn records_params<T: FromRow>(params: &ReaderParams) -> ReaderParams {
+    ReaderParams {
+        usecols: Some(T::columns()),
+        ..params.clone()
+    }
+}
+
+///load_txt_records reads in a data file where each row is read into a struct that implements the FromRow trait, which is
+///normally done using #[derive(FromRow)] with the derive feature. Each field of the struct is read in from a column of the data
+///that's given by its index or by its name, and each field is converted to its own type. So, a file with i64 ids, f64 measurements,
+///and String labels can be read straight into a Vec of structs. ReaderParams::usecols is ignored, since the columns that are used come
+///from the struct. It can fail in a number of ways related to invalid parameters, such as a column name that isn't in the data,
+///or the data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
+///If a malformed field is seen the error does contain information about what line number of the data file has the malformed field.
+///
+///Input -
+///
+/// f is simply the location of the file.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a Vec of our structs or an error.
+pub fn load_txt_records<T>(f: &str, params: &ReaderParams) -> Result<Vec<T>, ReaderError>
+where
+    T: FromRow + Send,
+    T::Row: Send,
+{
+    let results: Records<T> = parse_file(f, &records_params::<T>(params))?;
+    Ok(results.records)
+}
+
+///load_reader_records is the same as load_txt_records except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a Vec of our structs or an error.
+pub fn load_reader_records<T, R: BufRead>(reader: R, params: &ReaderParams) -> Result<Vec<T>, ReaderError>
+where
+    T: FromRow,
+{
+    let results: Records<T> = parse_reader(reader, &records_params::<T>(params))?;
+    Ok(results.records)
+}
+
+///load_bytes_records is the same as load_txt_records except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a Vec of our structs or an error.
+pub fn load_bytes_records<T>(bytes: &[u8], params: &ReaderParams) -> Result<Vec<T>, ReaderError>
+where
+    T: FromRow + Send,
+    T::Row: Send,
+{
+    let results: Records<T> = parse_slice(bytes, &records_params::<T>(params))?;
+    Ok(results.records)
+}
@@ -0,0 +1,305 @@
+// This file is a part of the Rust Data Reader Library
+// Copyright 2018 Robert Carson
+//
+//    Licensed under the Apache License, Version 2.0 (the "License");
+//    you may not use this file except in compliance with the License.
+//    You may obtain a copy of the License at
+//
+//        http://www.apache.org/licenses/LICENSE-2.0
+//
+//    Unless required by applicable law or agreed to in writing, software
+//    distributed under the License is distributed on an "AS IS" BASIS,
+//    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//    See the License for the specific language governing permissions and
+//    limitations under the License.
+use super::*;
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::BufReader;
+use std::mem;
+
+use super::missing::MissingFields;
+use super::parser::{check_num_lines, get_cols, get_missing, get_names, parse_buffer, parse_eof, read_header_names};
+use super::parser_core::{NwLine, ParserState, CoreData};
+use super::parser_utility::{skip_header_lines, FooterReader};
+#[cfg(any(feature = "gzip", feature = "zstd", feature = "bzip2", feature = "xz"))]
+use super::compression::Compression;
+
+///Where our rows are read from. If we have a footer to skip then those lines need to be held back from us.
+enum RowSource<R: BufRead> {
+    Reader(R),
+    Footer(FooterReader<R>),
+}
+
+impl<R: BufRead> RowSource<R> {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        match self {
+            RowSource::Reader(reader) => reader.fill_buf(),
+            RowSource::Footer(reader) => reader.fill_buf(),
+        }
+    }
+
+    fn consume(&mut self, amt: usize) {
+        match self {
+            RowSource::Reader(reader) => reader.consume(amt),
+            RowSource::Footer(reader) => reader.consume(amt),
+        }
+    }
+
+    fn num_held_lines(&self) -> usize {
+        match self {
+            RowSource::Reader(_) => 0,
+            RowSource::Footer(reader) => reader.num_held_lines(),
+        }
+    }
+}
+
+///Everything we need to pick our parser back up where it left off, so our data can be read in a few rows at a time.
+///It's shared by RowIter and Batches, which only differ in how many rows they ask for at once.
+pub(crate) struct RowStream<R: BufRead> {
+    reader: RowSource<R>,
+    pub params: ReaderParams,
+    cols: Vec<usize>,
+    missing: Option<MissingFields>,
+    pub names: Vec<String>,
+    //Where our parser left off within our data, which can be part way through a comment at the end of our last row
+    state: ParserState,
+    fln: usize,
+    pos: usize,
+    //The byte right before where our parser left off, which tells it whether a '\n' it starts on ends a "\r\n"
+    last_byte: u8,
+    tot_fields: usize,
+    num_rejected: usize,
+    //The number of lines of data that were read in, and the number that were skipped before our first row
+    num_lines: usize,
+    num_skipped: usize,
+}
+
+impl<R: BufRead> RowStream<R> {
+    ///Skips our header lines and reads in our column names, so our parser is ready to read in our first row.
+    ///Our params are saved off with row_errors swapped out for the one given.
+    pub(crate) fn new(mut reader: R, params: &ReaderParams, row_errors: RowErrors) -> Result<RowStream<R>, ReaderError> {
+        let cmt = params.comments.unwrap_or(b'\n');
+        let mut fln = 0;
+
+        let (sk_h, pos) = skip_header_lines(&mut reader, &mut fln, cmt, params.skip_header.unwrap_or_default())?;
+        let (names, nm_h, nm_pos) = read_header_names(&mut reader, &mut fln, params)?;
+        let cols = get_cols(params, &names)?;
+        let missing = get_missing(params, &names)?;
+
+        let sk_f = params.skip_footer.unwrap_or_default();
+        let reader = if sk_f > 0 { RowSource::Footer(FooterReader::new(reader, cmt, sk_f)) } else { RowSource::Reader(reader) };
+
+        Ok(RowStream {
+            reader,
+            params: ReaderParams { row_errors, ..params.clone() },
+            names: get_names(names, &cols, params.row_format),
+            cols,
+            missing,
+            state: ParserState::NwLine(NwLine{}),
+            fln,
+            pos: pos + nm_pos,
+            last_byte: 0,
+            tot_fields: 0,
+            num_rejected: 0,
+            num_lines: 0,
+            num_skipped: sk_h + nm_h,
+        })
+    }
+
+    ///The number of fields in each row, which is 0 until the first row has been read in if usecols isn't used.
+    pub(crate) fn num_fields(&self) -> usize {
+        if self.cols.is_empty() { self.tot_fields } else { self.cols.len() }
+    }
+
+    ///The most rows that are left to be read in when ReaderParams::max_rows is used.
+    pub(crate) fn num_lines_left(&self) -> usize {
+        self.params.max_rows.map_or(usize::MAX, |x| x.saturating_sub(self.num_lines))
+    }
+
+    ///Runs our parser until it has read in num_lines_read rows into results or we've run out of data.
+    ///Our results should be empty beforehand, and the number of rows that were read in is returned.
+    pub(crate) fn read<T: RawReaderParse>(&mut self, results: &mut T, num_lines_read: usize) -> Result<usize, ReaderError> {
+        let mut core_data = CoreData::new(&self.params, &self.cols, self.missing.as_ref(), results, self.tot_fields, self.fln, self.pos);
+        core_data.num_rejected = self.num_rejected;
+        core_data.last_byte = self.last_byte;
+        let mut state = mem::replace(&mut self.state, ParserState::NwLine(NwLine{}));
+
+        let parsed = loop {
+            let buffer = match self.reader.fill_buf() {
+                Ok(buffer) => buffer,
+                Err(err) => break Err(err.into()),
+            };
+            let length = buffer.len();
+            if length == 0 {
+                //A newline is fed to our parser at the end of our data, so we're always on a new line afterwards.
+                break parse_eof(state, &mut core_data, num_lines_read);
+            }
+            //Our state is moved into the parser, so we hold onto whatever it hands back for the next rows.
+            let last_byte = core_data.last_byte;
+            state = match parse_buffer(buffer, state, &mut core_data, num_lines_read) {
+                Ok(state) => state,
+                Err(err) => break Err(err),
+            };
+            //We only consume up to the end of our last row, so the next row starts right where this one left off.
+            let used = core_data.offset.min(length);
+            core_data.pos -= length - used;
+            core_data.last_byte = used.checked_sub(1).map_or(last_byte, |x| buffer[x]);
+            self.reader.consume(used);
+            if core_data.results.get_num_lines() == num_lines_read {
+                self.state = state;
+                break Ok(());
+            }
+        };
+
+        self.fln = core_data.fln;
+        self.pos = core_data.pos;
+        self.last_byte = core_data.last_byte;
+        self.tot_fields = core_data.tot_fields;
+        self.num_rejected = core_data.num_rejected;
+        parsed?;
+
+        let num_lines = results.get_num_lines();
+        self.num_lines += num_lines;
+        Ok(num_lines)
+    }
+
+    ///Checks that the parameters we provided were valid for the lines we've gone through in our data.
+    pub(crate) fn finish(&self) -> Result<(), ReaderError> {
+        check_num_lines(self.num_skipped + self.num_lines + self.num_rejected + self.reader.num_held_lines(), &self.params)
+    }
+}
+
+///Opens the file at location f for RowIter and Batches. If any of the gzip, zstd, bzip2, or xz features are turned on,
+///a compressed file is detected by its magic bytes or file extension and is decompressed as it's being read in.
+pub(crate) fn open_file(f: &str) -> Result<Box<dyn BufRead>, ReaderError> {
+    #[allow(unused_mut)]
+    let mut reader = BufReader::with_capacity(BUF_SIZE, File::open(f)?);
+    #[cfg(any(feature = "gzip", feature = "zstd", feature = "bzip2", feature = "xz"))]
+    if let Some(compression) = Compression::detect(f, reader.fill_buf()?) {
+        return compression.decoder(reader);
+    }
+    Ok(Box::new(reader))
+}
+
+///RowIter is an iterator that reads in our data one row at a time, so only a single row of our data ever lives in memory
+///no matter how large the data is. Each row is returned as a Vec of type T, where type P determines how the fields are converted
+///just like in TypedReaderResultsRows. The primitive number types use the default of LexicalParse, and any type that supports the
+///FromStr trait can use FromStrParse. Our data is only ever read through once and only as far as the rows that have been asked for.
+///
+///All of ReaderParams is supported. A line of data that can't be read in is returned as an error for that row and we move on to the
+///next line afterwards, unless row_errors is set to RowErrors::Skip in which case it's skipped over without an error. Any other errors
+///such as ones from reading in the data end the iteration once they've been returned.
+pub struct RowIter<T, R: BufRead, P = LexicalParse>
+where
+    P: ParseField<T>,
+{
+    stream: RowStream<R>,
+    results: TypedReaderResultsRows<T, P>,
+    //Lines that were skipped over before the row we've read in, which are returned first
+    errors: VecDeque<ReaderError>,
+    row: Option<Vec<T>>,
+    done: bool,
+}
+
+impl<T, R, P> RowIter<T, R, P>
+where
+    R: BufRead,
+    P: ParseField<T>,
+{
+    ///Creates our iterator over any type that supports the BufRead trait. The header lines are skipped and our column names
+    ///are read in right away, so errors from them are returned here.
+    pub fn new(reader: R, params: &ReaderParams) -> Result<RowIter<T, R, P>, ReaderError> {
+        //Lines with errors are always skipped over by the parser, so that we can keep on going afterwards.
+        //They're handed back to us unless we're skipping them without reporting them.
+        let row_errors = match params.row_errors {
+            RowErrors::Skip => RowErrors::Skip,
+            _ => RowErrors::SkipAndReport,
+        };
+        let stream = RowStream::new(reader, &ReaderParams { row_format: true, ..params.clone() }, row_errors)?;
+
+        Ok(RowIter {
+            results: TypedReaderResultsRows::new(stream.num_fields(), 1),
+            stream,
+            errors: VecDeque::new(),
+            row: None,
+            done: false,
+        })
+    }
+
+    ///The names of the columns that were read in when ReaderParams::names is used
+    pub fn names(&self) -> &[String] {
+        &self.stream.names
+    }
+
+    ///The number of fields in each row. This is 0 until the first row has been read in if usecols isn't used.
+    pub fn num_fields(&self) -> usize {
+        self.stream.num_fields()
+    }
+
+    ///Runs our parser until it has read in a single row or we've run out of data. Any lines that were skipped over
+    ///along the way are saved off, so they can be returned before the row.
+    fn read_row(&mut self) -> Result<(), ReaderError> {
+        let num_lines = self.stream.num_lines;
+        let read = self.stream.read(&mut self.results, 1)?;
+
+        //The rows of any fields that failed to convert need to take into account the rows we've already read in
+        self.errors.extend(self.results.rejected.drain(..).map(|mut rejected| {
+            rejected.reason.offset_row(num_lines);
+            rejected.reason
+        }));
+        self.results.filled.clear();
+        if read == 1 {
+            self.results.num_lines = 0;
+            let num_fields = self.results.num_fields;
+            self.row = Some(mem::replace(&mut self.results.results, Vec::with_capacity(num_fields)));
+        }
+        Ok(())
+    }
+}
+
+impl<T, P> RowIter<T, Box<dyn BufRead>, P>
+where
+    P: ParseField<T>,
+{
+    ///Creates our iterator over the file at location f. If any of the gzip, zstd, bzip2, or xz features are turned on,
+    ///a compressed file is detected by its magic bytes or file extension and is decompressed as it's being read in.
+    pub fn from_file(f: &str, params: &ReaderParams) -> Result<RowIter<T, Box<dyn BufRead>, P>, ReaderError> {
+        RowIter::new(open_file(f)?, params)
+    }
+}
+
+impl<T, R, P> Iterator for RowIter<T, R, P>
+where
+    R: BufRead,
+    P: ParseField<T>,
+{
+    type Item = Result<Vec<T>, ReaderError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(err) = self.errors.pop_front() {
+            return Some(Err(err));
+        }
+        if let Some(row) = self.row.take() {
+            return Some(Ok(row));
+        }
+        if self.done {
+            return None;
+        }
+
+        if self.stream.num_lines_left() > 0 {
+            if let Err(err) = self.read_row() {
+                self.done = true;
+                return Some(Err(err));
+            }
+        }
+
+        //Once we've run out of data or rows to read in, we can make sure that the parameters we provided were valid for it.
+        if self.row.is_none() && self.errors.is_empty() {
+            self.done = true;
+            return self.stream.finish().err().map(Err);
+        }
+        self.next()
+    }
+}
@@ -0,0 +1,301 @@
+// This file is a part of the Rust Data Reader Library
+// Copyright 2018 Robert Carson
+//
+//    Licensed under the Apache License, Version 2.0 (the "License");
+//    you may not use this file except in compliance with the License.
+//    You may obtain a copy of the License at
+//
+//        http://www.apache.org/licenses/LICENSE-2.0
+//
+//    Unless required by applicable law or agreed to in writing, software
+//    distributed under the License is distributed on an "AS IS" BASIS,
+//    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//    See the License for the specific language governing permissions and
+//    limitations under the License.
+use super::*;
+
+///The narrowest type that every field of a column that we've seen so far can be read in as. A column starts out as Empty
+///and is widened along bool -> i64 -> f64 -> String as we see fields that don't fit its current type. A bool column
+///can't hold numbers and a number column can't hold bools, so a column with both of them is widened to String.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Inferred {
+    Empty,
+    Bool,
+    I64,
+    F64,
+    String,
+}
+
+impl Inferred {
+    ///The narrowest type that a single field can be read in as
+    #[inline(always)]
+    fn from_field(bytes: &[u8]) -> Inferred {
+        if <LexicalParse as ParseField<i64>>::parse_field(bytes).is_ok() {
+            Inferred::I64
+        } else if <LexicalParse as ParseField<f64>>::parse_field(bytes).is_ok() {
+            Inferred::F64
+        } else if <FromStrParse as ParseField<bool>>::parse_field(bytes).is_ok() {
+            Inferred::Bool
+        } else {
+            Inferred::String
+        }
+    }
+
+    ///The narrowest type that can hold the fields of both types
+    #[inline(always)]
+    fn widen(self, other: Inferred) -> Inferred {
+        match (self, other) {
+            (Inferred::Bool, Inferred::I64 | Inferred::F64) | (Inferred::I64 | Inferred::F64, Inferred::Bool) => Inferred::String,
+            _ => self.max(other),
+        }
+    }
+
+    ///A column that only ever had missing fields could be anything, so it's left as a String which can hold them.
+    fn dtype(self) -> DataType {
+        match self {
+            Inferred::Bool => DataType::Bool,
+            Inferred::I64 => DataType::I64,
+            Inferred::F64 => DataType::F64,
+            Inferred::Empty | Inferred::String => DataType::String,
+        }
+    }
+}
+
+///What infer_schema found out about a single column of our data. dtype is the narrowest of bool, i64, f64, and String
+///that every field of the column can be read in as, and nullable is whether or not the column had any missing fields
+///that weren't filled in. A nullable column can be read in with the Option loaders such as `load_txt_opt::<T>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnSchema {
+    pub dtype: DataType,
+    pub nullable: bool,
+}
+
+///A structure that contains the type of each column of our data as found by infer_schema. It tells us the number of fields
+///we had along with the number of lines that we looked at. The type of each column is stored in columns, which are in the
+///same order as usecols if it's used. Any lines that were skipped over with RowErrors::SkipAndReport are stored in rejected.
+///If the names of the columns were read in they're stored in names.
+///
+///None of the fields are saved off, so our data can be as large as we like. A Schema can be handed to parse_txt or any of the
+///other parsers directly, but it's easiest to use infer_schema.
+#[derive(Debug, Clone)]
+pub struct Schema {
+    pub num_fields: usize,
+    pub num_lines: usize,
+    pub columns: Vec<ColumnSchema>,
+    pub rejected: Vec<RejectedLine>,
+    pub names: Vec<String>,
+    inferred: Vec<Inferred>,
+    //The types of the line that we're in the middle of, which only become a part of our columns once the line is done
+    line: Vec<Inferred>,
+    field: Vec<u8>,
+}
+
+impl Schema {
+    ///The type of each of our columns, which can be used as ReaderParams::dtypes to read in our data with load_txt_table
+    pub fn dtypes(&self) -> Vec<DataType> {
+        self.columns.iter().map(|x| x.dtype).collect()
+    }
+
+    ///Returns what we found out about the column with the given name if there is one
+    pub fn get_column_by_name(&self, name: &str) -> Option<&ColumnSchema> {
+        self.names.iter().position(|x| x == name).map(|index| &self.columns[index])
+    }
+
+    ///Our columns only take into account the lines that were fully read in, so a line that's rolled back never widens them.
+    fn finish_line(&mut self) {
+        for (icol, &inferred) in self.line.iter().enumerate() {
+            if inferred == Inferred::Empty {
+                self.columns[icol].nullable = true;
+            } else {
+                self.inferred[icol] = self.inferred[icol].widen(inferred);
+                self.columns[icol].dtype = self.inferred[icol].dtype();
+            }
+        }
+    }
+}
+
+impl RawReaderParse for Schema {
+    #[inline(always)]
+    fn new(field: usize, _num_lines: usize) -> Self {
+        let mut schema = Schema {
+            num_fields: 0,
+            num_lines: 0,
+            columns: Vec::<ColumnSchema>::with_capacity(field),
+            rejected: Vec::<RejectedLine>::new(),
+            names: Vec::<String>::new(),
+            inferred: Vec::<Inferred>::with_capacity(field),
+            line: Vec::<Inferred>::with_capacity(field),
+            field: Vec::<u8>::new(),
+        };
+        schema.set_num_fields(field);
+        schema
+    }
+
+    #[inline(always)]
+    fn get_num_lines(&self) -> usize {
+        self.num_lines
+    }
+
+    #[inline(always)]
+    fn incr_num_lines(&mut self) {
+        self.finish_line();
+        self.num_lines += 1;
+    }
+
+    #[inline(always)]
+    fn set_num_lines(&mut self, num_lines: usize) {
+        self.num_lines = num_lines;
+    }
+
+    #[inline(always)]
+    fn set_results(&mut self, value: u8, _field: usize) {
+        self.field.push(value);
+    }
+
+    #[inline(always)]
+    fn set_index(&mut self, field: usize) -> Result<(), ReaderError> {
+        self.line[field - 1] = if self.field.is_empty() { Inferred::Empty } else { Inferred::from_field(&self.field) };
+        self.field.clear();
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn set_num_fields(&mut self, field: usize) {
+        while self.num_fields < field {
+            self.columns.push(ColumnSchema { dtype: Inferred::Empty.dtype(), nullable: false });
+            self.inferred.push(Inferred::Empty);
+            self.line.push(Inferred::Empty);
+            self.num_fields += 1;
+        }
+    }
+
+    fn rollback_line(&mut self, num_fields: usize) {
+        self.num_fields = num_fields;
+        self.columns.truncate(self.num_fields);
+        self.inferred.truncate(self.num_fields);
+        self.line.truncate(self.num_fields);
+        self.field.clear();
+    }
+
+    fn add_rejected(&mut self, rejected: RejectedLine) {
+        self.rejected.push(rejected);
+    }
+
+    fn set_names(&mut self, names: Vec<String>) {
+        self.names = names;
+    }
+
+    fn get_field(&self, _field: usize) -> &[u8] {
+        &self.field
+    }
+
+    ///A filled in field counts as the value it was filled in with, so it's never missing unless it's filled in with nothing.
+    fn fill_field(&mut self, _field: usize, fill: &[u8]) {
+        self.field.clear();
+        self.field.extend_from_slice(fill);
+    }
+}
+
+impl RawReaderAppend for Schema {
+    fn append(&mut self, other: Self) {
+        //We need enough columns to hold everything from the other schema
+        self.set_num_fields(other.num_fields);
+        for (icol, column) in other.columns.iter().enumerate() {
+            self.inferred[icol] = self.inferred[icol].widen(other.inferred[icol]);
+            self.columns[icol].dtype = self.inferred[icol].dtype();
+            self.columns[icol].nullable |= column.nullable;
+        }
+        //The rows of any fields that failed to convert need to take into account our rows
+        let num_lines = self.num_lines;
+        self.rejected.extend(other.rejected.into_iter().map(|mut rejected| {
+            rejected.reason.offset_row(num_lines);
+            rejected
+        }));
+        self.num_lines += other.num_lines;
+    }
+}
+
+impl RawReaderResultsCols {
+    ///Works out the type of each of our columns from the fields that were read in, using the same rules as infer_schema.
+    ///This lets data that can only be read through once, such as from a reader, be read in and then have its types worked out.
+    ///Any empty fields are counted as missing.
+    pub fn infer_schema(&self) -> Schema {
+        let mut schema = <Schema as RawReaderParse>::new(self.num_fields, 0);
+        for icol in 0..self.num_fields {
+            for field in self.get_col_fields(icol) {
+                if field.is_empty() {
+                    schema.columns[icol].nullable = true;
+                } else {
+                    schema.inferred[icol] = schema.inferred[icol].widen(Inferred::from_field(field));
+                }
+            }
+            schema.columns[icol].dtype = schema.inferred[icol].dtype();
+        }
+        schema.num_lines = self.num_lines;
+        schema.rejected = self.rejected.clone();
+        schema.names = self.names.clone();
+        schema
+    }
+}
+
+///Just like a Table our columns are kept in the same order as usecols, so our names need to be in that order as well.
+fn schema_params(params: &ReaderParams) -> ReaderParams {
+    ReaderParams {
+        row_format: false,
+        ..params.clone()
+    }
+}
+
+///infer_schema reads through a data file to work out the narrowest type that each column can be read in as, along with whether
+///or not each column has any missing fields. The types that are tried out are bool, i64, f64, and String, where a column is widened
+///along that order as fields that don't fit its current type are seen. The types that are found can be handed straight to
+///ReaderParams::dtypes to read the data in with load_txt_table, or they can be used to pick which load_txt_* to use for each column.
+///None of the fields are saved off as the data is read through. If only a sample of the data should be looked at then
+///ReaderParams::max_rows can be used to limit the number of rows that are read. It can fail in a number of ways related to invalid
+///parameters or the data file having malformed lines. These errors are percolated up to whatever is calling this in the form of
+///the ReaderError type.
+///
+///Input -
+///
+/// f is simply the location of the file.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a Schema structure or an error.
+pub fn infer_schema(f: &str, params: &ReaderParams) -> Result<Schema, ReaderError> {
+    parse_file(f, &schema_params(params))
+}
+
+///infer_schema_reader is the same as infer_schema except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a Schema structure or an error.
+pub fn infer_schema_reader<R: BufRead>(reader: R, params: &ReaderParams) -> Result<Schema, ReaderError> {
+    parse_reader(reader, &schema_params(params))
+}
+
+///infer_schema_bytes is the same as infer_schema except that it reads through data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to look at.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a Schema structure or an error.
+pub fn infer_schema_bytes(bytes: &[u8], params: &ReaderParams) -> Result<Schema, ReaderError> {
+    parse_slice(bytes, &schema_params(params))
+}
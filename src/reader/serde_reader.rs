@@ -0,0 +1,384 @@
+// This file is a part of the Rust Data Reader Library
+// Copyright 2018 Robert Carson
+//
+//    Licensed under the Apache License, Version 2.0 (the "License");
+//    you may not use this file except in compliance with the License.
+//    You may obtain a copy of the License at
+//
+//        http://www.apache.org/licenses/LICENSE-2.0
+//
+//    Unless required by applicable law or agreed to in writing, software
+//    distributed under the License is distributed on an "AS IS" BASIS,
+//    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//    See the License for the specific language governing permissions and
+//    limitations under the License.
+use super::*;
+
+use std::fmt::Display;
+
+use serde::de::value::BorrowedStrDeserializer;
+use serde::de::{self, DeserializeOwned, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::{forward_to_deserialize_any, Deserialize, Deserializer};
+
+impl de::Error for ReaderError {
+    fn custom<T: Display>(msg: T) -> Self {
+        ReaderError::Deserialize {
+            row: 0,
+            column: None,
+            reason: msg.to_string(),
+        }
+    }
+}
+
+impl ReaderError {
+    ///Fills in the row of a deserialize error along with its column if it came from a single field and doesn't have one yet.
+    fn set_deserialize_location(mut self, row_val: usize, column_val: Option<usize>) -> ReaderError {
+        if let ReaderError::Deserialize { row, column, .. } = &mut self {
+            *row = row_val;
+            if column.is_none() {
+                *column = column_val;
+            }
+        }
+        self
+    }
+}
+
+impl RawReaderResultsRows {
+    ///Returns the bytes of every field in the given 0 indexed row
+    fn get_row_fields(&self, row: usize) -> Vec<&[u8]> {
+        let start = row * self.num_fields;
+        (start..start + self.num_fields)
+            .map(|i| {
+                let begin = if i == 0 { 0 } else { self.index[i - 1] };
+                &self.results[begin..self.index[i]]
+            })
+            .collect()
+    }
+
+    ///Deserializes the given 0 indexed row into any type that supports serde's Deserialize trait.
+    ///Strings can be borrowed straight from our results.
+    pub fn deserialize_row<'de, T: Deserialize<'de>>(&'de self, row: usize) -> Result<T, ReaderError> {
+        T::deserialize(RowDeserializer::new(self, row)).map_err(|err| err.set_deserialize_location(row, None))
+    }
+
+    ///Deserializes every row of our results into any type that supports serde's Deserialize trait.
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<Vec<T>, ReaderError> {
+        (0..self.num_lines).map(|row| self.deserialize_row(row)).collect()
+    }
+}
+
+///A serde Deserializer over a single row of RawReaderResultsRows. If the names of the columns were read in using ReaderParams::names,
+///a row can be deserialized as a struct or a map where the names are the keys. So, a #[derive(Deserialize)] struct has its fields filled in
+///from the columns with the same names, and a HashMap<String, f64> has an entry for every column. Without names a row can only be
+///deserialized as a sequence such as a tuple, a tuple struct, or a Vec, and structs have their fields filled in by position.
+///
+///Each field can be deserialized as any of the primitive types, a string, or bytes. An Option is None when the field is empty,
+///and a unit enum variant is given by its name.
+pub struct RowDeserializer<'de> {
+    fields: Vec<&'de [u8]>,
+    names: &'de [String],
+    row: usize,
+}
+
+impl<'de> RowDeserializer<'de> {
+    ///Creates a Deserializer over the given 0 indexed row of our results
+    pub fn new(results: &'de RawReaderResultsRows, row: usize) -> RowDeserializer<'de> {
+        RowDeserializer {
+            fields: results.get_row_fields(row),
+            names: &results.names,
+            row,
+        }
+    }
+
+    fn has_names(&self) -> bool {
+        !self.names.is_empty()
+    }
+}
+
+impl<'de> Deserializer<'de> for RowDeserializer<'de> {
+    type Error = ReaderError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ReaderError> {
+        if self.has_names() {
+            self.deserialize_map(visitor)
+        } else {
+            self.deserialize_seq(visitor)
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ReaderError> {
+        visitor.visit_seq(RowAccess { fields: self.fields.into_iter(), names: None, column: 0, row: self.row })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, ReaderError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(self, _name: &'static str, _len: usize, visitor: V) -> Result<V::Value, ReaderError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ReaderError> {
+        if !self.has_names() {
+            return Err(de::Error::custom("a row can only be deserialized as a map or struct if ReaderParams::names was used"));
+        }
+        visitor.visit_map(RowAccess { fields: self.fields.into_iter(), names: Some(self.names.iter()), column: 0, row: self.row })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ReaderError> {
+        self.deserialize_any(visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct enum identifier ignored_any
+    }
+}
+
+///Walks through the fields of a row as either a sequence or a map, where the names of the columns are the keys.
+struct RowAccess<'de> {
+    fields: std::vec::IntoIter<&'de [u8]>,
+    names: Option<std::slice::Iter<'de, String>>,
+    column: usize,
+    row: usize,
+}
+
+impl<'de> RowAccess<'de> {
+    ///Deserializes the next field, so that any error knows which column it came from.
+    fn next_field<T: DeserializeSeed<'de>>(&mut self, seed: T, bytes: &'de [u8]) -> Result<T::Value, ReaderError> {
+        let column = self.column;
+        self.column += 1;
+        seed.deserialize(FieldDeserializer { bytes }).map_err(|err| err.set_deserialize_location(self.row, Some(column)))
+    }
+}
+
+impl<'de> SeqAccess<'de> for RowAccess<'de> {
+    type Error = ReaderError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, ReaderError> {
+        match self.fields.next() {
+            Some(bytes) => self.next_field(seed, bytes).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len())
+    }
+}
+
+impl<'de> MapAccess<'de> for RowAccess<'de> {
+    type Error = ReaderError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, ReaderError> {
+        match self.names.as_mut().and_then(|x| x.next()) {
+            Some(name) => seed.deserialize(BorrowedStrDeserializer::new(name)).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, ReaderError> {
+        match self.fields.next() {
+            Some(bytes) => self.next_field(seed, bytes),
+            None => Err(de::Error::custom("a row has more column names than fields")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len())
+    }
+}
+
+///A serde Deserializer over the bytes of a single field
+struct FieldDeserializer<'de> {
+    bytes: &'de [u8],
+}
+
+impl<'de> FieldDeserializer<'de> {
+    fn parse<T, P: ParseField<T>>(&self) -> Result<T, ReaderError> {
+        P::parse_field(self.bytes).map_err(|err| de::Error::custom(err_reason(err, self.bytes)))
+    }
+
+    fn as_str(&self) -> Result<&'de str, ReaderError> {
+        str::from_utf8(self.bytes).map_err(|err| de::Error::custom(format!("{:?} is not valid UTF-8: {}", String::from_utf8_lossy(self.bytes), err)))
+    }
+}
+
+///Our field conversion errors are turned into a reason that a deserialize error can hold.
+fn err_reason(err: ReaderError, bytes: &[u8]) -> String {
+    match err {
+        ReaderError::Conversion { reason, .. } => format!("{:?} could not be converted: {}", String::from_utf8_lossy(bytes), reason),
+        err => err.to_string(),
+    }
+}
+
+macro_rules! deserialize_field {
+    ($($method:ident, $visit:ident, $type:ty, $parse:ty;)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ReaderError> {
+                visitor.$visit(self.parse::<$type, $parse>()?)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for FieldDeserializer<'de> {
+    type Error = ReaderError;
+
+    ///Fields that could be anything are read in as the first type that they convert to out of bool, i64, u64, f64, and string.
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ReaderError> {
+        if let Ok(x) = self.parse::<bool, FromStrParse>() {
+            visitor.visit_bool(x)
+        } else if let Ok(x) = self.parse::<i64, LexicalParse>() {
+            visitor.visit_i64(x)
+        } else if let Ok(x) = self.parse::<u64, LexicalParse>() {
+            visitor.visit_u64(x)
+        } else if let Ok(x) = self.parse::<f64, LexicalParse>() {
+            visitor.visit_f64(x)
+        } else {
+            visitor.visit_borrowed_str(self.as_str()?)
+        }
+    }
+
+    deserialize_field! {
+        deserialize_bool, visit_bool, bool, FromStrParse;
+        deserialize_i8, visit_i8, i8, LexicalParse;
+        deserialize_i16, visit_i16, i16, LexicalParse;
+        deserialize_i32, visit_i32, i32, LexicalParse;
+        deserialize_i64, visit_i64, i64, LexicalParse;
+        deserialize_i128, visit_i128, i128, LexicalParse;
+        deserialize_u8, visit_u8, u8, LexicalParse;
+        deserialize_u16, visit_u16, u16, LexicalParse;
+        deserialize_u32, visit_u32, u32, LexicalParse;
+        deserialize_u64, visit_u64, u64, LexicalParse;
+        deserialize_u128, visit_u128, u128, LexicalParse;
+        deserialize_f32, visit_f32, f32, LexicalParse;
+        deserialize_f64, visit_f64, f64, LexicalParse;
+        deserialize_char, visit_char, char, FromStrParse;
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ReaderError> {
+        visitor.visit_borrowed_str(self.as_str()?)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ReaderError> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ReaderError> {
+        visitor.visit_borrowed_bytes(self.bytes)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ReaderError> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ReaderError> {
+        if self.bytes.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ReaderError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, ReaderError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, ReaderError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ReaderError> {
+        visitor.visit_enum(BorrowedStrDeserializer::<ReaderError>::new(self.as_str()?))
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ReaderError> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ReaderError> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        seq tuple tuple_struct map struct
+    }
+}
+
+///Our rows are always read in row major order, and the names need to stay in the same order as the fields of each row.
+fn serde_params(params: &ReaderParams) -> ReaderParams {
+    ReaderParams {
+        row_format: true,
+        ..params.clone()
+    }
+}
+
+///load_txt_serde reads in a data file where each row is deserialized into any type that supports serde's Deserialize trait, which
+///is available with the serde feature. If the names of the columns are read in using ReaderParams::names, the names are used as
+///the keys of each row. So, a #[derive(Deserialize)] struct has its fields filled in from the columns with the same names, and
+///a HashMap<String, f64> has an entry for every column. Without names each row is deserialized as a sequence such as a tuple.
+///It can fail in a number of ways related to invalid parameters, the data file having malformed fields, or a row not being
+///able to be deserialized. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
+///
+///Input -
+///
+/// f is simply the location of the file.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a Vec of our deserialized rows or an error.
+pub fn load_txt_serde<T: DeserializeOwned>(f: &str, params: &ReaderParams) -> Result<Vec<T>, ReaderError> {
+    let results: RawReaderResultsRows = parse_file(f, &serde_params(params))?;
+    results.deserialize()
+}
+
+///load_reader_serde is the same as load_txt_serde except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a Vec of our deserialized rows or an error.
+pub fn load_reader_serde<T: DeserializeOwned, R: BufRead>(reader: R, params: &ReaderParams) -> Result<Vec<T>, ReaderError> {
+    let results: RawReaderResultsRows = parse_reader(reader, &serde_params(params))?;
+    results.deserialize()
+}
+
+///load_bytes_serde is the same as load_txt_serde except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a Vec of our deserialized rows or an error.
+pub fn load_bytes_serde<T: DeserializeOwned>(bytes: &[u8], params: &ReaderParams) -> Result<Vec<T>, ReaderError> {
+    let results: RawReaderResultsRows = parse_slice(bytes, &serde_params(params))?;
+    results.deserialize()
+}
@@ -0,0 +1,225 @@
+// This file is a part of the Rust Data Reader Library
+// Copyright 2018 Robert Carson
+//
+//    Licensed under the Apache License, Version 2.0 (the "License");
+//    you may not use this file except in compliance with the License.
+//    You may obtain a copy of the License at
+//
+//        http://www.apache.org/licenses/LICENSE-2.0
+//
+//    Unless required by applicable law or agreed to in writing, software
+//    distributed under the License is distributed on an "AS IS" BASIS,
+//    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//    See the License for the specific language governing permissions and
+//    limitations under the License.
+use super::*;
+
+use std::fs::File;
+use std::io::BufReader;
+
+///The number of lines at the start of our data that sniff, sniff_reader, and sniff_bytes look at.
+pub const SNIFF_LINES: usize = 100;
+
+//The comment characters and delimiters that we try out. If more than one of them works equally well the earlier one wins.
+const COMMENTS: [u8; 3] = [b'#', b'%', b'!'];
+const DELIMITERS: [Delimiter; 5] = [Delimiter::Any(b','), Delimiter::Any(b'\t'), Delimiter::Any(b';'), Delimiter::Any(b'|'), Delimiter::WhiteSpace];
+
+///The layout of our data that was found by sniff_dialect. The delimiter, comments, and names can be handed straight to
+///ReaderParams, which is what params does. num_fields is the number of fields in each line of data.
+///
+/// names - Names::Header if the first line of data is the names of our columns, or Names::Comment if the commented line
+///     right before our data is. It's None if neither of them look like names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dialect {
+    pub delimiter: Delimiter,
+    pub comments: Option<u8>,
+    pub names: Option<Names>,
+    pub num_fields: usize,
+}
+
+impl Dialect {
+    ///The ReaderParams that will read in our data, where everything that wasn't sniffed out is left as the default.
+    pub fn params(&self) -> ReaderParams {
+        ReaderParams {
+            delimiter: self.delimiter,
+            comments: self.comments,
+            names: self.names,
+            ..Default::default()
+        }
+    }
+}
+
+///sniff_dialect works out the layout of our data from its first num_lines lines, similar to Python's csv.Sniffer.
+///The comment character is whichever of #, %, or ! starts the most lines. The delimiter is whichever of a comma, tab, semicolon,
+///pipe, or whitespace splits every line of data into the same number of fields, where the one with the most fields is used.
+///If none of them do then whitespace is used. The first line of data is taken to be a header if any of its fields aren't numbers
+///while the rest of their column is. Otherwise, a commented line right before our data can be the names if none of its fields
+///are numbers. If there aren't any lines of data then we just get back the default layout with num_fields as 0.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait. Only the lines we look at are read from it.
+///
+/// num_lines is the number of lines at the start of our data that we look at including any commented lines.
+///
+///Output -
+///
+/// A Result type that either contains a Dialect or an error from reading in the data.
+pub fn sniff_dialect<R: BufRead>(reader: &mut R, num_lines: usize) -> Result<Dialect, ReaderError> {
+    let mut sample = Vec::<u8>::new();
+    for _ in 0..num_lines {
+        if reader.read_until(b'\n', &mut sample)? == 0 {
+            break;
+        }
+    }
+
+    //Any comment character takes lines out of the ones that we count as data
+    let tot_lines = read_num_file_lines(&mut &sample[..], b'\n');
+    let comments = COMMENTS
+        .iter()
+        .map(|&cmt| (cmt, tot_lines - read_num_file_lines(&mut &sample[..], cmt)))
+        .fold(None, |best: Option<(u8, usize)>, x| if (x.1 > 0) & best.is_none_or(|best| x.1 > best.1) { Some(x) } else { best })
+        .map(|x| x.0);
+    let cmt = comments.unwrap_or(b'\n');
+
+    let lines: Vec<&[u8]> = sample
+        .split(|&x| (x == b'\n') | (x == b'\r'))
+        .filter(|line| line.trim_ascii_start().first().is_some_and(|&x| x != cmt))
+        .collect();
+
+    let mut dialect = Dialect {
+        delimiter: Delimiter::WhiteSpace,
+        comments,
+        names: None,
+        num_fields: 0,
+    };
+    if lines.is_empty() {
+        return Ok(dialect);
+    }
+
+    //The first line might be a header, so it doesn't get a say in how many fields there are unless it's all we have
+    let data = if lines.len() > 1 { &lines[1..] } else { &lines[..] };
+    for delimiter in DELIMITERS {
+        let num_fields = count_fields(data[0], cmt, delimiter);
+        if (num_fields > 1) & (num_fields > dialect.num_fields) & data.iter().all(|line| count_fields(line, cmt, delimiter) == num_fields) {
+            dialect.delimiter = delimiter;
+            dialect.num_fields = num_fields;
+        }
+    }
+    if dialect.num_fields == 0 {
+        dialect.num_fields = count_fields(data[0], cmt, Delimiter::WhiteSpace);
+    }
+
+    if has_header(&lines, cmt, dialect.delimiter) {
+        dialect.names = Some(Names::Header);
+    }
+    else if let Some(line) = comment_names(&sample, cmt) {
+        let names = split_fields(line, b'\n', dialect.delimiter);
+        if (names.len() == dialect.num_fields) & !names.iter().any(|x| is_number(x)) {
+            dialect.names = Some(Names::Comment);
+        }
+    }
+    Ok(dialect)
+}
+
+///sniff works out the ReaderParams for the file at location f by looking at its first SNIFF_LINES lines using sniff_dialect.
+///
+///Input -
+///
+/// f is simply the location of the file.
+///
+///Output -
+///
+/// A Result type that either contains the ReaderParams for our data or an error from reading in the data.
+pub fn sniff(f: &str) -> Result<ReaderParams, ReaderError> {
+    let mut reader = BufReader::with_capacity(BUF_SIZE, File::open(f)?);
+    sniff_reader(&mut reader)
+}
+
+///sniff_reader is the same as sniff except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+///Output -
+///
+/// A Result type that either contains the ReaderParams for our data or an error from reading in the data.
+pub fn sniff_reader<R: BufRead>(reader: &mut R) -> Result<ReaderParams, ReaderError> {
+    Ok(sniff_dialect(reader, SNIFF_LINES)?.params())
+}
+
+///sniff_bytes is the same as sniff except that it looks at data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to look at.
+///
+///Output -
+///
+/// A Result type that either contains the ReaderParams for our data or an error.
+pub fn sniff_bytes(bytes: &[u8]) -> Result<ReaderParams, ReaderError> {
+    sniff_reader(&mut &bytes[..])
+}
+
+///The number of fields in a single line of data using the same rules that the parser uses.
+fn count_fields(line: &[u8], cmt: u8, delimiter: Delimiter) -> usize {
+    match delimiter {
+        Delimiter::WhiteSpace => count_num_fields(&mut &line[..], cmt, b' ', true),
+        Delimiter::Any(delim) => count_num_fields(&mut &line[..], cmt, delim, false),
+    }
+}
+
+///Splits a line up into its fields with any comment at the end of it removed along with whitespace and quotes around each field.
+fn split_fields(line: &[u8], cmt: u8, delimiter: Delimiter) -> Vec<&[u8]> {
+    let line = line.split(|&x| x == cmt).next().unwrap_or_default();
+    let fields: Vec<&[u8]> = match delimiter {
+        Delimiter::WhiteSpace => line.split(|x| x.is_ascii_whitespace()).filter(|x| !x.is_empty()).collect(),
+        Delimiter::Any(delim) => line.split(|&x| x == delim).map(|x| x.trim_ascii()).collect(),
+    };
+    fields
+        .into_iter()
+        .map(|x| x.strip_prefix(b"\"").and_then(|x| x.strip_suffix(b"\"")).unwrap_or(x))
+        .collect()
+}
+
+fn is_number(field: &[u8]) -> bool {
+    <LexicalParse as ParseField<f64>>::parse_field(field).is_ok()
+}
+
+///Our first line is a header if it has a field that isn't a number where the rest of its column is all numbers,
+///and it doesn't have more fields that are numbers in columns of numbers.
+fn has_header(lines: &[&[u8]], cmt: u8, delimiter: Delimiter) -> bool {
+    if lines.len() < 2 {
+        return false;
+    }
+    let header = split_fields(lines[0], cmt, delimiter);
+    let data: Vec<Vec<&[u8]>> = lines[1..].iter().map(|line| split_fields(line, cmt, delimiter)).collect();
+    if data.iter().any(|fields| fields.len() != header.len()) {
+        return false;
+    }
+
+    let mut votes = 0i64;
+    for (icol, name) in header.iter().enumerate() {
+        if data.iter().all(|fields| is_number(fields[icol])) {
+            votes += if is_number(name) { -1 } else { 1 };
+        }
+    }
+    votes > 0
+}
+
+///The commented line right before our first line of data with the comment character taken off of it, if there is one.
+fn comment_names(sample: &[u8], cmt: u8) -> Option<&[u8]> {
+    let mut names = None;
+    for line in sample.split(|&x| (x == b'\n') | (x == b'\r')) {
+        let line = line.trim_ascii_start();
+        match line.first() {
+            None => {}
+            Some(&x) if x == cmt => names = Some(&line[1..]),
+            _ => return names,
+        }
+    }
+    None
+}
@@ -0,0 +1,346 @@
+// This file is a part of the Rust Data Reader Library
+// Copyright 2018 Robert Carson
+//
+//    Licensed under the Apache License, Version 2.0 (the "License");
+//    you may not use this file except in compliance with the License.
+//    You may obtain a copy of the License at
+//
+//        http://www.apache.org/licenses/LICENSE-2.0
+//
+//    Unless required by applicable law or agreed to in writing, software
+//    distributed under the License is distributed on an "AS IS" BASIS,
+//    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//    See the License for the specific language governing permissions and
+//    limitations under the License.
+use super::*;
+
+//Every type that a column can be read in as along with how its fields are converted.
+//The DataType and ColumnData enums along with everything that needs to match on them are built from this list.
+macro_rules! data_types {
+    ($mac:ident) => {
+        $mac! {
+            Bool(bool, FromStrParse, as_bool),
+            Char(char, FromStrParse, as_char),
+            I8(i8, LexicalParse, as_i8),
+            I16(i16, LexicalParse, as_i16),
+            I32(i32, LexicalParse, as_i32),
+            I64(i64, LexicalParse, as_i64),
+            U8(u8, LexicalParse, as_u8),
+            U16(u16, LexicalParse, as_u16),
+            U32(u32, LexicalParse, as_u32),
+            U64(u64, LexicalParse, as_u64),
+            F32(f32, LexicalParse, as_f32),
+            F64(f64, LexicalParse, as_f64),
+            String(String, FromStrParse, as_string),
+        }
+    };
+}
+
+macro_rules! column_data {
+    ($($name:ident($type:ty, $parse:ty, $as:ident),)*) => {
+        ///The type that a column is read in as when using ReaderParams::dtypes.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum DataType {
+            $($name,)*
+        }
+
+        ///The values of a single column of a Table, which are stored as a Vec of the column's type.
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum ColumnData {
+            $($name(Vec<$type>),)*
+        }
+
+        impl ColumnData {
+            ///Creates an empty column for the given type
+            fn new(dtype: DataType) -> ColumnData {
+                match dtype {
+                    $(DataType::$name => ColumnData::$name(Vec::<$type>::new()),)*
+                }
+            }
+
+            ///Returns the type of our column
+            pub fn dtype(&self) -> DataType {
+                match self {
+                    $(ColumnData::$name(_) => DataType::$name,)*
+                }
+            }
+
+            ///Returns the number of values in our column
+            pub fn len(&self) -> usize {
+                match self {
+                    $(ColumnData::$name(x) => x.len(),)*
+                }
+            }
+
+            ///Returns whether or not our column has any values in it
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            $(
+                ///Returns the values of our column if it's of this type
+                pub fn $as(&self) -> Option<&[$type]> {
+                    match self {
+                        ColumnData::$name(x) => Some(x),
+                        _ => None,
+                    }
+                }
+            )*
+
+            ///Converts the bytes of a field to the type of our column and saves it off
+            #[inline(always)]
+            fn push(&mut self, bytes: &[u8]) -> Result<(), ReaderError> {
+                match self {
+                    $(ColumnData::$name(x) => x.push(<$parse as ParseField<$type>>::parse_field(bytes)?),)*
+                }
+                Ok(())
+            }
+
+            ///Only keeps the first num_lines values of our column
+            fn truncate(&mut self, num_lines: usize) {
+                match self {
+                    $(ColumnData::$name(x) => x.truncate(num_lines),)*
+                }
+            }
+
+            ///Adds the values of another column of the same type onto the end of our column
+            fn extend(&mut self, other: ColumnData) {
+                match (self, other) {
+                    $((ColumnData::$name(x), ColumnData::$name(y)) => x.extend(y),)*
+                    _ => unreachable!("Columns of a Table always have the same type"),
+                }
+            }
+        }
+    };
+}
+
+data_types!(column_data);
+
+///A structure that contains all of the results when each column has its own type, which is given by ReaderParams::dtypes.
+///It tells us the number of fields we had along with the number of lines that we read. Each column is stored as its own
+///Vec of its type in columns. Any lines that were skipped over with RowErrors::SkipAndReport are stored in rejected.
+///If the names of the columns were read in they're stored in names. The row and column of any fields that were missing
+///and filled in are stored in filled.
+///
+///Since each field is converted as soon as it's been parsed, a Table can be handed to parse_txt or any of the other parsers
+///directly, but it's easiest to use load_txt_table.
+#[derive(Debug, Clone)]
+pub struct Table {
+    pub num_fields: usize,
+    pub num_lines: usize,
+    pub columns: Vec<ColumnData>,
+    pub rejected: Vec<RejectedLine>,
+    pub names: Vec<String>,
+    pub filled: Vec<(usize, usize)>,
+    dtypes: Vec<DataType>,
+    field: Vec<u8>,
+}
+
+impl Table {
+    ///Returns the column at the given index
+    pub fn get_column(&self, col_index: usize) -> &ColumnData {
+        &self.columns[col_index]
+    }
+
+    ///Returns the column with the given name if there is one
+    pub fn get_column_by_name(&self, name: &str) -> Option<&ColumnData> {
+        self.names.iter().position(|x| x == name).map(|index| &self.columns[index])
+    }
+}
+
+impl RawReaderParse for Table {
+    #[inline(always)]
+    fn new(field: usize, _num_lines: usize) -> Self {
+        Table {
+            num_fields: field,
+            num_lines: 0,
+            columns: Vec::<ColumnData>::with_capacity(field),
+            rejected: Vec::<RejectedLine>::new(),
+            names: Vec::<String>::new(),
+            filled: Vec::<(usize, usize)>::new(),
+            dtypes: Vec::<DataType>::new(),
+            field: Vec::<u8>::new(),
+        }
+    }
+
+    ///Our columns are created once we know what types they are
+    fn set_params(&mut self, params: &ReaderParams) {
+        self.dtypes = params.dtypes.clone().unwrap_or_default();
+        self.columns = self.dtypes.iter().take(self.num_fields).map(|&x| ColumnData::new(x)).collect();
+    }
+
+    #[inline(always)]
+    fn get_num_lines(&self) -> usize {
+        self.num_lines
+    }
+
+    #[inline(always)]
+    fn incr_num_lines(&mut self) {
+        self.num_lines += 1;
+    }
+
+    #[inline(always)]
+    fn set_num_lines(&mut self, num_lines: usize) {
+        self.num_lines = num_lines;
+    }
+
+    #[inline(always)]
+    fn set_results(&mut self, value: u8, _field: usize) {
+        self.field.push(value);
+    }
+
+    #[inline(always)]
+    fn set_index(&mut self, field: usize) -> Result<(), ReaderError> {
+        //The field is cleared out even if it failed to convert, so we're ready for the next one.
+        let temp = match self.columns.get_mut(field - 1) {
+            Some(column) => column.push(&self.field),
+            None => Err(dtypes_error(self.dtypes.len(), field)),
+        };
+        self.field.clear();
+        temp
+    }
+
+    #[inline(always)]
+    fn set_num_fields(&mut self, field: usize) {
+        while self.num_fields < field {
+            if let Some(&dtype) = self.dtypes.get(self.num_fields) {
+                self.columns.push(ColumnData::new(dtype));
+            }
+            self.num_fields += 1;
+        }
+    }
+
+    fn rollback_line(&mut self, num_fields: usize) {
+        self.num_fields = num_fields;
+        self.columns.truncate(self.num_fields);
+        for column in self.columns.iter_mut() {
+            column.truncate(self.num_lines);
+        }
+        self.field.clear();
+        rollback_filled(&mut self.filled, self.num_lines);
+    }
+
+    fn add_rejected(&mut self, rejected: RejectedLine) {
+        self.rejected.push(rejected);
+    }
+
+    fn set_names(&mut self, names: Vec<String>) {
+        self.names = names;
+    }
+
+    fn get_field(&self, _field: usize) -> &[u8] {
+        &self.field
+    }
+
+    fn fill_field(&mut self, field: usize, fill: &[u8]) {
+        self.field.clear();
+        self.field.extend_from_slice(fill);
+        self.filled.push((self.num_lines, field - 1));
+    }
+}
+
+impl RawReaderAppend for Table {
+    fn append(&mut self, other: Self) {
+        //We need enough columns to hold everything from the other results
+        self.set_num_fields(other.num_fields);
+        for (icol, column) in other.columns.into_iter().enumerate() {
+            self.columns[icol].extend(column);
+        }
+        //The rows of any fields that failed to convert need to take into account our rows
+        let num_lines = self.num_lines;
+        self.rejected.extend(other.rejected.into_iter().map(|mut rejected| {
+            rejected.reason.offset_row(num_lines);
+            rejected
+        }));
+        self.filled.extend(other.filled.iter().map(|&(row, col)| (row + num_lines, col)));
+        self.num_lines += other.num_lines;
+    }
+}
+
+///Returns the error for when our dtypes don't line up with the columns that we're reading in.
+#[cold]
+pub(crate) fn dtypes_error(num_dtypes: usize, num_fields: usize) -> ReaderError {
+    ReaderError::InvalidParams {
+        field: "dtypes",
+        reason: format!("contains {} types but {} columns are being read in", num_dtypes, num_fields),
+    }
+}
+
+///Makes sure that our dtypes were given and that there was a type for every column once the data has been read in.
+fn check_dtypes(table: Table) -> Result<Table, ReaderError> {
+    if table.num_fields > 0 && table.dtypes.len() != table.num_fields {
+        return Err(dtypes_error(table.dtypes.len(), table.num_fields));
+    }
+    Ok(table)
+}
+
+///Our dtypes need to be given before there's any point in reading in the data. A Table is column oriented, so just like
+///RawReaderResultsCols its names are kept in the same order as usecols, which is also the order of our dtypes.
+fn table_params(params: &ReaderParams) -> Result<ReaderParams, ReaderError> {
+    match &params.dtypes {
+        Some(_) => Ok(ReaderParams {
+            row_format: false,
+            ..params.clone()
+        }),
+        None => Err(ReaderError::InvalidParams {
+            field: "dtypes",
+            reason: String::from("is required to read in a Table"),
+        }),
+    }
+}
+
+///load_txt_table reads in a data file where each column has its own type, such as i64 ids, f64 measurements, bool flags, and String labels.
+///The type of each column is given by ReaderParams::dtypes, which has one type for each column that's read in. If usecols is used the types
+///are in the same order as usecols, and otherwise they're in the same order as the columns of the data. The data is only read through once,
+///and each field is converted to its column's type as soon as it's been parsed. It can fail in a number of ways related to invalid parameters,
+///such as dtypes not having a type for every column, or the data file having malformed fields. These errors are percolated up to whatever is
+///calling this in the form of the ReaderError type. If a malformed field is seen the error does contain information about what line number
+///of the data file has the malformed field.
+///
+///Input -
+///
+/// f is simply the location of the file.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a Table structure or an error.
+pub fn load_txt_table(f: &str, params: &ReaderParams) -> Result<Table, ReaderError> {
+    let params = table_params(params)?;
+    check_dtypes(parse_file(f, &params)?)
+}
+
+///load_reader_table is the same as load_txt_table except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a Table structure or an error.
+pub fn load_reader_table<R: BufRead>(reader: R, params: &ReaderParams) -> Result<Table, ReaderError> {
+    let params = table_params(params)?;
+    check_dtypes(parse_reader(reader, &params)?)
+}
+
+///load_bytes_table is the same as load_txt_table except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a Table structure or an error.
+pub fn load_bytes_table(bytes: &[u8], params: &ReaderParams) -> Result<Table, ReaderError> {
+    let params = table_params(params)?;
+    check_dtypes(parse_slice(bytes, &params)?)
+}
@@ -16,7 +16,7 @@ use super::*;
 
 ///load_txt_u8 reads in a data file that is made up of u8 type data. If this assumption is not made then the parser will fail
 ///during the conversion between strings to u8. It can also fail in a number of other ways related to invalid parameters or the
-///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the Error type.
+///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
 ///One should therefore check to make sure no errors are obtained when examining the file. If a malformed field is seen the error
 ///does contain information about what line number of the data file has the malformed field.
 ///
@@ -29,13 +29,45 @@ use super::*;
 ///Output -
 ///
 /// A Result type that either contains a ReaderResults structure or an error.
-pub fn load_txt_u8(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<u8>>, Error> {
+pub fn load_txt_u8(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<u8>>, ReaderError> {
     load_text_lexical!(f, params, u8)
 }
 
+///load_reader_u8 is the same as load_txt_u8 except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_reader_u8<R: BufRead>(reader: R, params: &ReaderParams) -> Result<Box<dyn ReaderResults<u8>>, ReaderError> {
+    load_text_lexical!(@reader reader, params, u8)
+}
+
+///load_bytes_u8 is the same as load_txt_u8 except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_bytes_u8(bytes: &[u8], params: &ReaderParams) -> Result<Box<dyn ReaderResults<u8>>, ReaderError> {
+    load_text_lexical!(@bytes bytes, params, u8)
+}
+
 ///load_txt_u16 reads in a data file that is made up of u16 type data. If this assumption is not made then the parser will fail
 ///during the conversion between strings to u16. It can also fail in a number of other ways related to invalid parameters or the
-///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the Error type.
+///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
 ///One should therefore check to make sure no errors are obtained when examining the file. If a malformed field is seen the error
 ///does contain information about what line number of the data file has the malformed field.
 ///
@@ -48,13 +80,45 @@ pub fn load_txt_u8(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResul
 ///Output -
 ///
 /// A Result type that either contains a ReaderResults structure or an error.
-pub fn load_txt_u16(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<u16>>, Error> {
+pub fn load_txt_u16(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<u16>>, ReaderError> {
     load_text_lexical!(f, params, u16)
 }
 
+///load_reader_u16 is the same as load_txt_u16 except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_reader_u16<R: BufRead>(reader: R, params: &ReaderParams) -> Result<Box<dyn ReaderResults<u16>>, ReaderError> {
+    load_text_lexical!(@reader reader, params, u16)
+}
+
+///load_bytes_u16 is the same as load_txt_u16 except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_bytes_u16(bytes: &[u8], params: &ReaderParams) -> Result<Box<dyn ReaderResults<u16>>, ReaderError> {
+    load_text_lexical!(@bytes bytes, params, u16)
+}
+
 ///load_txt_u32 reads in a data file that is made up of u32 type data. If this assumption is not made then the parser will fail
 ///during the conversion between strings to u32. It can also fail in a number of other ways related to invalid parameters or the
-///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the Error type.
+///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
 ///One should therefore check to make sure no errors are obtained when examining the file. If a malformed field is seen the error
 ///does contain information about what line number of the data file has the malformed field.
 ///
@@ -67,13 +131,45 @@ pub fn load_txt_u16(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResu
 ///Output -
 ///
 /// A Result type that either contains a ReaderResults structure or an error.
-pub fn load_txt_u32(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<u32>>, Error> {
+pub fn load_txt_u32(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<u32>>, ReaderError> {
     load_text_lexical!(f, params, u32)
 }
 
+///load_reader_u32 is the same as load_txt_u32 except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_reader_u32<R: BufRead>(reader: R, params: &ReaderParams) -> Result<Box<dyn ReaderResults<u32>>, ReaderError> {
+    load_text_lexical!(@reader reader, params, u32)
+}
+
+///load_bytes_u32 is the same as load_txt_u32 except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_bytes_u32(bytes: &[u8], params: &ReaderParams) -> Result<Box<dyn ReaderResults<u32>>, ReaderError> {
+    load_text_lexical!(@bytes bytes, params, u32)
+}
+
 ///load_txt_u64 reads in a data file that is made up of u64 type data. If this assumption is not made then the parser will fail
 ///during the conversion between strings to u64. It can also fail in a number of other ways related to invalid parameters or the
-///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the Error type.
+///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
 ///One should therefore check to make sure no errors are obtained when examining the file. If a malformed field is seen the error
 ///does contain information about what line number of the data file has the malformed field.
 ///
@@ -86,13 +182,45 @@ pub fn load_txt_u32(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResu
 ///Output -
 ///
 /// A Result type that either contains a ReaderResults structure or an error.
-pub fn load_txt_u64(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<u64>>, Error> {
+pub fn load_txt_u64(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<u64>>, ReaderError> {
     load_text_lexical!(f, params, u64)
 }
 
+///load_reader_u64 is the same as load_txt_u64 except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_reader_u64<R: BufRead>(reader: R, params: &ReaderParams) -> Result<Box<dyn ReaderResults<u64>>, ReaderError> {
+    load_text_lexical!(@reader reader, params, u64)
+}
+
+///load_bytes_u64 is the same as load_txt_u64 except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_bytes_u64(bytes: &[u8], params: &ReaderParams) -> Result<Box<dyn ReaderResults<u64>>, ReaderError> {
+    load_text_lexical!(@bytes bytes, params, u64)
+}
+
 ///load_txt_u128 reads in a data file that is made up of u128 type data. If this assumption is not made then the parser will fail
 ///during the conversion between strings to u128. It can also fail in a number of other ways related to invalid parameters or the
-///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the Error type.
+///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
 ///One should therefore check to make sure no errors are obtained when examining the file. If a malformed field is seen the error
 ///does contain information about what line number of the data file has the malformed field.
 ///
@@ -105,13 +233,45 @@ pub fn load_txt_u64(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResu
 ///Output -
 ///
 /// A Result type that either contains a ReaderResults structure or an error.
-pub fn load_txt_u128(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<u128>>, Error> {
+pub fn load_txt_u128(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<u128>>, ReaderError> {
     load_text_lexical!(f, params, u128)
 }
 
+///load_reader_u128 is the same as load_txt_u128 except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_reader_u128<R: BufRead>(reader: R, params: &ReaderParams) -> Result<Box<dyn ReaderResults<u128>>, ReaderError> {
+    load_text_lexical!(@reader reader, params, u128)
+}
+
+///load_bytes_u128 is the same as load_txt_u128 except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_bytes_u128(bytes: &[u8], params: &ReaderParams) -> Result<Box<dyn ReaderResults<u128>>, ReaderError> {
+    load_text_lexical!(@bytes bytes, params, u128)
+}
+
 ///load_txt_usize reads in a data file that is made up of usize type data. If this assumption is not made then the parser will fail
 ///during the conversion between strings to usize. It can also fail in a number of other ways related to invalid parameters or the
-///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the Error type.
+///data file having malformed fields. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
 ///One should therefore check to make sure no errors are obtained when examining the file. If a malformed field is seen the error
 ///does contain information about what line number of the data file has the malformed field.
 ///
@@ -125,6 +285,38 @@ pub fn load_txt_u128(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderRes
 ///Output -
 ///
 /// A Result type that either contains a ReaderResults structure or an error.
-pub fn load_txt_usize(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<usize>>, Error> {
+pub fn load_txt_usize(f: &str, params: &ReaderParams) -> Result<Box<dyn ReaderResults<usize>>, ReaderError> {
     load_text_lexical!(f, params, usize)
 }
+
+///load_reader_usize is the same as load_txt_usize except that it reads the data from any type that supports
+///the BufRead trait rather than from a file location.
+///
+///Input -
+///
+/// reader is the data source which must support the BufRead trait.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_reader_usize<R: BufRead>(reader: R, params: &ReaderParams) -> Result<Box<dyn ReaderResults<usize>>, ReaderError> {
+    load_text_lexical!(@reader reader, params, usize)
+}
+
+///load_bytes_usize is the same as load_txt_usize except that it parses data that already lives in memory as a slice
+///of bytes rather than reading it from a file location.
+///
+///Input -
+///
+/// bytes is the data that we want to parse.
+///
+/// params is ReaderParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that either contains a ReaderResults structure or an error.
+pub fn load_bytes_usize(bytes: &[u8], params: &ReaderParams) -> Result<Box<dyn ReaderResults<usize>>, ReaderError> {
+    load_text_lexical!(@bytes bytes, params, usize)
+}
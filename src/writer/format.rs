@@ -0,0 +1,290 @@
+// This file is a part of the Rust Data Reader Library
+// Copyright 2018 Robert Carson
+//
+//    Licensed under the Apache License, Version 2.0 (the "License");
+//    you may not use this file except in compliance with the License.
+//    You may obtain a copy of the License at
+//
+//        http://www.apache.org/licenses/LICENSE-2.0
+//
+//    Unless required by applicable law or agreed to in writing, software
+//    distributed under the License is distributed on an "AS IS" BASIS,
+//    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//    See the License for the specific language governing permissions and
+//    limitations under the License.
+use crate::reader::ReaderError;
+
+use std::fmt::Write;
+
+///The conversion at the end of a printf-style format, which tells us how a value is written out.
+///
+/// Int - %d, %i, or %u, where floats have their fractional part dropped
+///
+/// Fixed - %f or %F
+///
+/// Exp - %e or %E, where the exponent always has a sign and at least two digits
+///
+/// General - %g or %G, which is whichever of Fixed and Exp is shorter with any trailing zeros removed
+///
+/// Str - %s, which writes the value out the same way as when no format is given
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Conversion {
+    Int,
+    Fixed,
+    Exp,
+    General,
+    Str,
+}
+
+///A printf-style format such as `%.6e`, `%10.4f`, or `%-8s` that a column is written out with.
+///Just like C and numpy's savetxt, the flags `-`, `+`, ` `, `0`, and `#` can come before the width and precision.
+///Any text before or after the conversion is written out along with the value, where `%%` is a single `%`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Format {
+    prefix: String,
+    suffix: String,
+    left: bool,
+    plus: bool,
+    space: bool,
+    zero: bool,
+    alt: bool,
+    upper: bool,
+    width: usize,
+    precision: Option<usize>,
+    conversion: Conversion,
+}
+
+///Returns the error for when a format couldn't be used.
+#[cold]
+fn format_error(reason: String) -> ReaderError {
+    ReaderError::InvalidParams { field: "formats", reason }
+}
+
+///Replaces any %% in the text around our conversion with a single %, where any other % is an error.
+fn unescape(text: &str, format: &str) -> Result<String, ReaderError> {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '%' && chars.next() != Some('%') {
+            return Err(format_error(format!("contains {:?} which can only have a single conversion", format)));
+        }
+        out.push(c);
+    }
+    Ok(out)
+}
+
+impl Format {
+    ///Parses a printf-style format, which needs to have exactly one conversion in it. It fails if the format is malformed
+    ///or uses a conversion that we don't support.
+    pub fn new(format: &str) -> Result<Format, ReaderError> {
+        //Our conversion starts at the first % that isn't a part of a %%
+        let bytes = format.as_bytes();
+        let mut start = None;
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == b'%' {
+                if bytes.get(i + 1) == Some(&b'%') {
+                    i += 2;
+                    continue;
+                }
+                start = Some(i);
+                break;
+            }
+            i += 1;
+        }
+        let start = start.ok_or_else(|| format_error(format!("contains {:?} which doesn't have a conversion such as %f", format)))?;
+
+        let mut fmt = Format {
+            prefix: unescape(&format[..start], format)?,
+            suffix: String::new(),
+            left: false,
+            plus: false,
+            space: false,
+            zero: false,
+            alt: false,
+            upper: false,
+            width: 0,
+            precision: None,
+            conversion: Conversion::Str,
+        };
+
+        let mut i = start + 1;
+        while let Some(&c) = bytes.get(i) {
+            match c {
+                b'-' => fmt.left = true,
+                b'+' => fmt.plus = true,
+                b' ' => fmt.space = true,
+                b'0' => fmt.zero = true,
+                b'#' => fmt.alt = true,
+                _ => break,
+            }
+            i += 1;
+        }
+        while let Some(c) = bytes.get(i).filter(|x| x.is_ascii_digit()) {
+            fmt.width = fmt.width * 10 + (c - b'0') as usize;
+            i += 1;
+        }
+        if bytes.get(i) == Some(&b'.') {
+            i += 1;
+            let mut precision = 0;
+            while let Some(c) = bytes.get(i).filter(|x| x.is_ascii_digit()) {
+                precision = precision * 10 + (c - b'0') as usize;
+                i += 1;
+            }
+            fmt.precision = Some(precision);
+        }
+        //Length modifiers such as the l in %ld don't mean anything for us
+        while let Some(b'h' | b'l' | b'L' | b'q' | b'j' | b'z' | b't') = bytes.get(i) {
+            i += 1;
+        }
+
+        fmt.conversion = match bytes.get(i) {
+            Some(b'd' | b'i' | b'u') => Conversion::Int,
+            Some(b'f' | b'F') => Conversion::Fixed,
+            Some(b'e' | b'E') => Conversion::Exp,
+            Some(b'g' | b'G') => Conversion::General,
+            Some(b's') => Conversion::Str,
+            _ => return Err(format_error(format!("contains {:?} which doesn't have a supported conversion", format))),
+        };
+        fmt.upper = matches!(bytes.get(i), Some(b'F' | b'E' | b'G'));
+        fmt.suffix = unescape(&format[i + 1..], format)?;
+        Ok(fmt)
+    }
+
+    ///Returns the conversion of our format
+    pub fn conversion(&self) -> Conversion {
+        self.conversion
+    }
+
+    ///Writes out a float using our format. Int drops the fractional part of the value, and Str writes it out as it is.
+    pub fn write_float(&self, value: f64, out: &mut String) {
+        let body = match self.conversion {
+            Conversion::Int if value.is_finite() => self.int_digits(value.trunc().abs() as u128),
+            Conversion::Int | Conversion::Fixed => self.fixed(value.abs()),
+            Conversion::Exp => self.exp(value.abs()),
+            Conversion::General => self.general(value.abs()),
+            Conversion::Str => return self.write_str(&value.to_string(), out),
+        };
+        self.write_number(value.is_sign_negative() && !value.is_nan(), value.is_finite(), &body, out);
+    }
+
+    ///Writes out an integer using our format. The float conversions write it out as a float, and Str writes it out as it is.
+    pub fn write_int(&self, value: i128, out: &mut String) {
+        match self.conversion {
+            Conversion::Int => {
+                let body = self.int_digits(value.unsigned_abs());
+                self.write_number(value < 0, true, &body, out);
+            }
+            Conversion::Str => self.write_str(&value.to_string(), out),
+            _ => self.write_float(value as f64, out),
+        }
+    }
+
+    ///Writes out text using our format, where the precision is the most characters that are written out.
+    pub fn write_str(&self, value: &str, out: &mut String) {
+        let value = match self.precision {
+            Some(precision) if self.conversion == Conversion::Str => value.char_indices().nth(precision).map_or(value, |(i, _)| &value[..i]),
+            _ => value,
+        };
+        out.push_str(&self.prefix);
+        self.pad(value, "", false, out);
+        out.push_str(&self.suffix);
+    }
+
+    ///The digits of an integer, where the precision is the least digits that are written out.
+    fn int_digits(&self, value: u128) -> String {
+        format!("{:0width$}", value, width = self.precision.unwrap_or(1))
+    }
+
+    fn fixed(&self, value: f64) -> String {
+        if !value.is_finite() {
+            return self.non_finite(value);
+        }
+        let precision = self.precision.unwrap_or(6);
+        let mut body = format!("{:.*}", precision, value);
+        if self.alt && precision == 0 {
+            body.push('.');
+        }
+        body
+    }
+
+    fn exp(&self, value: f64) -> String {
+        if !value.is_finite() {
+            return self.non_finite(value);
+        }
+        let precision = self.precision.unwrap_or(6);
+        let body = format!("{:.*e}", precision, value);
+        //Rust writes out 1.5e2 where C writes out 1.5e+02
+        let (mantissa, exponent) = body.split_once('e').expect("the exponent format always has an e");
+        let exponent: i32 = exponent.parse().expect("the exponent is always an integer");
+        let dot = if self.alt && precision == 0 { "." } else { "" };
+        let e = if self.upper { 'E' } else { 'e' };
+        format!("{}{}{}{}{:02}", mantissa, dot, e, if exponent < 0 { '-' } else { '+' }, exponent.abs())
+    }
+
+    fn general(&self, value: f64) -> String {
+        if !value.is_finite() {
+            return self.non_finite(value);
+        }
+        let precision = self.precision.unwrap_or(6).max(1);
+        //The exponent that our value has once it's been rounded to precision digits decides which style is used
+        let exponent: i32 = if value == 0.0 {
+            0
+        } else {
+            let body = format!("{:.*e}", precision - 1, value);
+            body.split_once('e').and_then(|(_, x)| x.parse().ok()).expect("the exponent is always an integer")
+        };
+        let style = Format {
+            precision: Some(if (-4..precision as i32).contains(&exponent) { (precision as i32 - 1 - exponent) as usize } else { precision - 1 }),
+            ..self.clone()
+        };
+        let mut body = if (-4..precision as i32).contains(&exponent) { style.fixed(value) } else { style.exp(value) };
+        if !self.alt {
+            let (mantissa, exponent) = match body.find(['e', 'E']) {
+                Some(i) => body.split_at(i),
+                None => (&body[..], ""),
+            };
+            let mantissa = if mantissa.contains('.') { mantissa.trim_end_matches('0').trim_end_matches('.') } else { mantissa };
+            body = format!("{}{}", mantissa, exponent);
+        }
+        body
+    }
+
+    fn non_finite(&self, value: f64) -> String {
+        let body = if value.is_nan() { "nan" } else { "inf" };
+        if self.upper {
+            body.to_uppercase()
+        } else {
+            String::from(body)
+        }
+    }
+
+    ///Writes out the sign along with the digits of a number padded out to our width.
+    fn write_number(&self, negative: bool, finite: bool, body: &str, out: &mut String) {
+        let sign = if negative {
+            "-"
+        } else if self.plus {
+            "+"
+        } else if self.space {
+            " "
+        } else {
+            ""
+        };
+        out.push_str(&self.prefix);
+        self.pad(body, sign, finite, out);
+        out.push_str(&self.suffix);
+    }
+
+    ///Pads out our value to our width, where numbers can be padded with zeros after their sign.
+    fn pad(&self, body: &str, sign: &str, zeros: bool, out: &mut String) {
+        let len = sign.len() + body.chars().count();
+        let padding = self.width.saturating_sub(len);
+        if self.left {
+            let _ = write!(out, "{}{}{:padding$}", sign, body, "", padding = padding);
+        } else if self.zero && zeros {
+            let _ = write!(out, "{}{:0>padding$}{}", sign, "", body, padding = padding);
+        } else {
+            let _ = write!(out, "{:padding$}{}{}", "", sign, body, padding = padding);
+        }
+    }
+}
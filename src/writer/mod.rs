@@ -0,0 +1,300 @@
+// This file is a part of the Rust Data Reader Library
+// Copyright 2018 Robert Carson
+//
+//    Licensed under the Apache License, Version 2.0 (the "License");
+//    you may not use this file except in compliance with the License.
+//    You may obtain a copy of the License at
+//
+//        http://www.apache.org/licenses/LICENSE-2.0
+//
+//    Unless required by applicable law or agreed to in writing, software
+//    distributed under the License is distributed on an "AS IS" BASIS,
+//    WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//    See the License for the specific language governing permissions and
+//    limitations under the License.
+
+use crate::reader::{Delimiter, ReaderError, ReaderResults};
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+/// Contains the printf-style formats that our columns can be written out with
+pub mod format;
+
+pub use self::format::*;
+
+///WriterParams tells us how our writer should write out our results, similar to numpy's savetxt.
+///
+/// delimiter - the delimiter that goes between the fields of each line, where Delimiter::WhiteSpace is a single space
+///
+/// newline - what goes at the end of each line such as `\n` or `\r\n`
+///
+/// header - an optional field that is written out at the start of the file. Each of its lines starts with comments.
+///
+/// footer - an optional field that is written out at the end of the file. Each of its lines starts with comments.
+///
+/// comments - what goes at the start of each line of the header and footer, so that they can be read back in as comments
+///
+/// names - whether or not the names of our columns are written out as the first line of data, which can be read back in
+///     with Names::Header. Nothing is written out if our results don't have names.
+///
+/// formats - an optional field that gives the printf-style format such as `%.6e` or `%10.4f` that our fields are written out with.
+///     It can either have a single format that's used for every column or a format for each column. Without a format each field
+///     is written out the same way as Display, which is read back in as the same value.
+///
+/// quote - an optional field that gives the quote character for any fields that contain the delimiter, the quote character,
+///     the start of comments, or a newline, along with empty fields when the delimiter is Delimiter::WhiteSpace. A quote within
+///     a quoted field is escaped by doubling it. Without a quote character any field that needs to be quoted is an error.
+///
+/// missing - what is written out for fields that are None, which is empty by default. With Delimiter::WhiteSpace an empty field
+///     needs to be quoted, so a token such as `NA` can be used along with ReaderParams::missing_values instead.
+#[derive(Debug, Clone)]
+pub struct WriterParams {
+    pub delimiter: Delimiter,
+    pub newline: String,
+    pub header: Option<String>,
+    pub footer: Option<String>,
+    pub comments: String,
+    pub names: bool,
+    pub formats: Option<Vec<String>>,
+    pub quote: Option<u8>,
+    pub missing: String,
+}
+
+///You can use the default constructor like this:
+///
+///let params = WriterParams::default(); or you could do
+///
+///something like -
+///let params = WriterParams{
+///        delimiter: Delimiter::Any(b','),
+///        ..Default::default()
+///};
+impl Default for WriterParams {
+    fn default() -> WriterParams {
+        WriterParams {
+            delimiter: Delimiter::WhiteSpace,
+            newline: String::from("\n"),
+            header: None,
+            footer: None,
+            comments: String::from("# "),
+            names: false,
+            formats: None,
+            quote: None,
+            missing: String::new(),
+        }
+    }
+}
+
+///A type that can be written out as a field. Without a format a field is written out the same way as Display.
+///Numbers can be written out with any conversion, while everything else can only be written out with %s.
+pub trait WriteField {
+    fn write_field(&self, format: Option<&Format>, missing: &str, out: &mut String) -> Result<(), ReaderError>;
+}
+
+macro_rules! write_field {
+    ($method:ident, $cast:ty, $($type:ty),*) => {
+        $(
+            impl WriteField for $type {
+                #[inline(always)]
+                fn write_field(&self, format: Option<&Format>, _missing: &str, out: &mut String) -> Result<(), ReaderError> {
+                    match format {
+                        Some(format) if format.conversion() != Conversion::Str => format.$method(*self as $cast, out),
+                        Some(format) => format.write_str(&self.to_string(), out),
+                        None => out.push_str(&self.to_string()),
+                    }
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+write_field!(write_int, i128, i8, i16, i32, i64, u8, u16, u32, u64);
+write_field!(write_float, f64, f32, f64);
+
+macro_rules! write_text_field {
+    ($($type:ty),*) => {
+        $(
+            impl WriteField for $type {
+                fn write_field(&self, format: Option<&Format>, _missing: &str, out: &mut String) -> Result<(), ReaderError> {
+                    match format {
+                        Some(format) if format.conversion() != Conversion::Str => {
+                            return Err(ReaderError::InvalidParams {
+                                field: "formats",
+                                reason: format!("has a number format for a column of {}", stringify!($type)),
+                            });
+                        }
+                        Some(format) => format.write_str(&self.to_string(), out),
+                        None => out.push_str(&self.to_string()),
+                    }
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+write_text_field!(bool, char, String);
+
+impl<T: WriteField> WriteField for Option<T> {
+    #[inline(always)]
+    fn write_field(&self, format: Option<&Format>, missing: &str, out: &mut String) -> Result<(), ReaderError> {
+        match self {
+            Some(value) => value.write_field(format, missing, out),
+            None => {
+                out.push_str(missing);
+                Ok(())
+            }
+        }
+    }
+}
+
+///Works out which format each of our columns uses, where a single format is used for every column.
+fn column_formats(params: &WriterParams, num_fields: usize) -> Result<Vec<Option<Format>>, ReaderError> {
+    match &params.formats {
+        None => Ok(vec![None; num_fields]),
+        Some(formats) if formats.len() == 1 => Ok(vec![Some(Format::new(&formats[0])?); num_fields]),
+        Some(formats) if formats.len() == num_fields => formats.iter().map(|x| Format::new(x).map(Some)).collect(),
+        Some(formats) => Err(ReaderError::InvalidParams {
+            field: "formats",
+            reason: format!("contains {} formats but there are {} columns", formats.len(), num_fields),
+        }),
+    }
+}
+
+///Writes out each line of text with our comments at the start of it.
+fn write_commented<W: Write>(writer: &mut W, text: &str, params: &WriterParams) -> Result<(), ReaderError> {
+    for line in text.lines() {
+        write!(writer, "{}{}{}", params.comments, line, params.newline)?;
+    }
+    Ok(())
+}
+
+///Adds a field onto our line, where it's quoted if it would otherwise be read back in as something else.
+///Any padding from our formats is only spaces around the value, which are trimmed off when the field is read back in,
+///so it's left out when deciding whether or not the value needs to be quoted. It fails if the field needs to be quoted
+///and we don't have a quote character, since the field couldn't be read back in as the same value.
+fn push_field(line: &mut String, field: &str, params: &WriterParams) -> Result<(), ReaderError> {
+    let value = field.trim_matches(' ');
+    let needs_quote = value.bytes().any(|x| {
+        (Some(x) == params.quote)
+            || (x == b'\n')
+            || (x == b'\r')
+            || match params.delimiter {
+                Delimiter::WhiteSpace => x.is_ascii_whitespace(),
+                Delimiter::Any(delim) => x == delim,
+            }
+    }) || (!params.comments.trim().is_empty() && value.contains(params.comments.trim()))
+        || (value.is_empty() && params.delimiter == Delimiter::WhiteSpace);
+    match params.quote {
+        Some(quote) if needs_quote => {
+            let quote = quote as char;
+            line.push(quote);
+            for c in field.chars() {
+                if c == quote {
+                    line.push(quote);
+                }
+                line.push(c);
+            }
+            line.push(quote);
+        }
+        None if needs_quote => {
+            return Err(ReaderError::InvalidParams {
+                field: "quote",
+                reason: format!("is needed to write out {:?} so that it's read back in as the same value", field),
+            });
+        }
+        _ => line.push_str(field),
+    }
+    Ok(())
+}
+
+///save_writer is the same as save_txt except that it writes our results out to any type that supports
+///the Write trait rather than to a file location.
+///
+///Input -
+///
+/// writer is where our results are written out to, which must support the Write trait.
+///
+/// results is the results that we want to write out.
+///
+/// params is WriterParams structure.
+///
+///Output -
+///
+/// A Result type that is either empty or contains an error.
+pub fn save_writer<T, W>(mut writer: W, results: &dyn ReaderResults<T>, params: &WriterParams) -> Result<(), ReaderError>
+where
+    T: Clone + WriteField,
+    W: Write,
+{
+    let num_fields = results.get_num_fields();
+    let formats = column_formats(params, num_fields)?;
+    let delimiter = match params.delimiter {
+        Delimiter::WhiteSpace => ' ',
+        Delimiter::Any(delim) => delim as char,
+    };
+
+    if let Some(header) = &params.header {
+        write_commented(&mut writer, header, params)?;
+    }
+
+    let mut line = String::new();
+    let mut field = String::new();
+    if params.names && !results.get_names().is_empty() {
+        for (icol, name) in results.get_names().iter().enumerate() {
+            if icol > 0 {
+                line.push(delimiter);
+            }
+            push_field(&mut line, name, params)?;
+        }
+        line.push_str(&params.newline);
+        writer.write_all(line.as_bytes())?;
+    }
+
+    for irow in 0..results.get_num_lines() {
+        line.clear();
+        for (icol, format) in formats.iter().enumerate() {
+            if icol > 0 {
+                line.push(delimiter);
+            }
+            field.clear();
+            results.get_value(irow, icol).write_field(format.as_ref(), &params.missing, &mut field)?;
+            push_field(&mut line, &field, params)?;
+        }
+        line.push_str(&params.newline);
+        writer.write_all(line.as_bytes())?;
+    }
+
+    if let Some(footer) = &params.footer {
+        write_commented(&mut writer, footer, params)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+///save_txt writes our results out to a delimited text file, similar to numpy's savetxt. It can write out a header and footer
+///as comments, the names of our columns, and each column with its own printf-style format such as `%.6e` or `%10.4f`.
+///Results that are written out with the same delimiter, comments, and quote as they're read in with are read back in as the same
+///values by load_txt_* when no formats are used. It can fail if our formats are malformed or don't line up with our columns,
+///if a field needs to be quoted and there's no quote character, or if the file can't be written to. These errors are percolated up to whatever is calling this in the form of the ReaderError type.
+///
+///Input -
+///
+/// f is simply the location of the file, which is created if it doesn't exist and overwritten if it does.
+///
+/// results is the results that we want to write out, such as the results from load_txt_f64.
+///
+/// params is WriterParams structure. An example for what this looks like can be found in the test directory.
+///
+///Output -
+///
+/// A Result type that is either empty or contains an error.
+pub fn save_txt<T>(f: &str, results: &dyn ReaderResults<T>, params: &WriterParams) -> Result<(), ReaderError>
+where
+    T: Clone + WriteField,
+{
+    let writer = BufWriter::new(File::create(f)?);
+    save_writer(writer, results, params)
+}
@@ -197,11 +197,31 @@ fn load_txt_i32_test_sk_f_big() {
     assert!(results.is_err());
 }
 
+//Data without any readable lines has its own error, while skip values that are too large name the parameter at fault
+#[test]
+fn load_bytes_no_data_test() {
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        ..Default::default()
+    };
+    for data in ["", "% only a comment\n\n   \n"] {
+        assert!(matches!(load_bytes_i32(data.as_bytes(), &params), Err(ReaderError::NoData)));
+        let mut rows = RowIter::<i32, _>::new(data.as_bytes(), &params).unwrap();
+        assert!(matches!(rows.next(), Some(Err(ReaderError::NoData))));
+    }
+
+    let data = "% comment\n1 2\n3 4\n";
+    let params = ReaderParams { skip_header: Some(2), ..params };
+    assert!(matches!(load_bytes_i32(data.as_bytes(), &params), Err(ReaderError::InvalidParams { field: "skip_header", .. })));
+    let params = ReaderParams { skip_header: None, skip_footer: Some(2), ..params };
+    assert!(matches!(load_bytes_i32(data.as_bytes(), &params), Err(ReaderError::InvalidParams { field: "skip_footer", .. })));
+}
+
 #[test]
 fn load_txt_i32_test_u_cols() {
     let file = String::from("int_testv2.txt");
 
-    let cols: Vec<usize> = vec![2];
+    let cols: Vec<Column> = vec![2.into()];
 
     let params = ReaderParams {
         comments: Some(b'%'),
@@ -229,7 +249,7 @@ fn load_txt_i32_test_u_cols() {
 fn load_txt_i32_test_u_cols_at_bnds() {
     let file = String::from("int_testv2.txt");
 
-    let cols: Vec<usize> = vec![3];
+    let cols: Vec<Column> = vec![3.into()];
 
     let params = ReaderParams {
         comments: Some(b'%'),
@@ -254,7 +274,7 @@ fn load_txt_i32_test_u_cols_at_bnds() {
 fn load_txt_i32_test_u_cols_out_bnds() {
     let file = String::from("int_testv2.txt");
 
-    let cols: Vec<usize> = vec![5];
+    let cols: Vec<Column> = vec![5.into()];
 
     let params = ReaderParams {
         comments: Some(b'%'),
@@ -413,6 +433,11 @@ fn load_txt_i64_test_cols() {
         usecols: None,
         max_rows: None,
         row_format: false,
+        row_errors: RowErrors::Fail,
+        names: None,
+        quote: None,
+        missing_values: None,
+        dtypes: None,
     };
 
     let results = load_txt_i64(&file, &params);
@@ -437,9 +462,14 @@ fn load_txt_i64_test_cols_skc() {
         delimiter: Delimiter::WhiteSpace,
         skip_header: None,
         skip_footer: None,
-        usecols: Some(vec![0,2]),
+        usecols: Some(vec![0.into(), 2.into()]),
         max_rows: None,
         row_format: false,
+        row_errors: RowErrors::Fail,
+        names: None,
+        quote: None,
+        missing_values: None,
+        dtypes: None,
     };
 
     let results = load_txt_i64(&file, &params);
@@ -961,3 +991,1972 @@ fn get_cols_test_fail() {
 
     let _value = results.get_cols(col_indices);
 }
+
+//Reads the data from an in memory reader rather than a file location
+#[test]
+fn load_reader_i32_test() {
+    let data = "1 2 3\n4 5 6\n7 8 9\n";
+    let reader = std::io::Cursor::new(data.as_bytes());
+
+    let params = ReaderParams::default();
+
+    let results = load_reader_i32(reader, &params).unwrap();
+
+    assert_eq!(*results.get_results(), vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(results.get_num_lines(), 3);
+}
+
+//The last line of data doesn't end with a newline character
+#[test]
+fn load_reader_i32_no_eol_test() {
+    let data = "1 2 3\n4 5 6\n7 8 9";
+    let reader = std::io::Cursor::new(data.as_bytes());
+
+    let params = ReaderParams {
+        row_format: false,
+        ..Default::default()
+    };
+
+    let results = load_reader_i32(reader, &params).unwrap();
+
+    assert_eq!(*results.get_results(), vec![1, 4, 7, 2, 5, 8, 3, 6, 9]);
+    assert_eq!(results.get_num_lines(), 3);
+}
+
+//A tiny buffer means that both data lines and commented lines end up split across buffers
+#[test]
+fn load_reader_i32_small_buffer_test() {
+    let file = File::open("int_testv3.txt").unwrap();
+    let reader = BufReader::with_capacity(4, file);
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        skip_header: Some(1),
+        skip_footer: Some(1),
+        ..Default::default()
+    };
+
+    let results = load_reader_i32(reader, &params).unwrap();
+
+    assert_eq!(
+        *results.get_results(),
+        vec![4, 5, 6, 10, 11, 12, 13, 14, 15, 19, 20, 21, 25, 26, 27]
+    );
+}
+
+//The custom type version of the reader
+#[test]
+fn load_reader_custom_test() -> Result<(), anyhow::Error> {
+    let data = "% a comment\n1, 2\n3, 4\n";
+    let reader = std::io::Cursor::new(data.as_bytes());
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        delimiter: Delimiter::Any(b','),
+        ..Default::default()
+    };
+
+    let ref_params = &params;
+
+    let results: Result<Box<dyn ReaderResults<MinInt>>, Error> = load_text!(@reader reader, ref_params, MinInt);
+
+    let vals: Vec<i32> = results?.get_results().iter().map(|x| x.x).collect();
+
+    assert_eq!(vals, vec![-1, -2, -3, -4]);
+
+    Ok(())
+}
+
+//Parses the data directly from a string that's already in memory
+#[test]
+fn load_bytes_f64_test() {
+    let data = "% x y\n1.0 2.5\n-3.0 4e2\n% trailing comment\n5.0 6.0";
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        skip_header: Some(1),
+        ..Default::default()
+    };
+
+    let results = load_bytes_f64(data.as_bytes(), &params).unwrap();
+
+    assert_eq!(*results.get_results(), vec![-3.0, 400.0, 5.0, 6.0]);
+    assert_eq!(results.get_num_lines(), 2);
+}
+
+#[test]
+fn parse_bytes_test() {
+    let data = b"a,b,c\nd,e,f\n";
+
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        usecols: Some(vec![0.into(), 2.into()]),
+        row_format: false,
+        ..Default::default()
+    };
+
+    let results = parse_bytes::<RawReaderResultsCols>(data, &params).unwrap();
+
+    assert_eq!(results.num_fields, 2);
+    assert_eq!(results.num_lines, 2);
+    assert_eq!(results.results, vec![b"ad".to_vec(), b"cf".to_vec()]);
+
+    let strings = load_bytes_string(data, &params).unwrap();
+
+    assert_eq!(strings.get_col(1), vec![String::from("c"), String::from("f")]);
+}
+
+//Fields are converted as they're parsed, which includes fields that are split up across buffers.
+#[test]
+fn parse_typed_test() {
+    let data = "1.5,2.25,-3e2\n% comment\n4.125,5,6.5\n";
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        delimiter: Delimiter::Any(b','),
+        usecols: Some(vec![0.into(), 2.into()]),
+        row_format: false,
+        ..Default::default()
+    };
+
+    let results = parse_bytes::<TypedReaderResultsCols<f64>>(data.as_bytes(), &params).unwrap();
+
+    assert_eq!(results.num_fields, 2);
+    assert_eq!(results.num_lines, 2);
+    assert_eq!(results.results, vec![vec![1.5, 4.125], vec![-3e2, 6.5]]);
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        delimiter: Delimiter::Any(b','),
+        ..Default::default()
+    };
+
+    let reader = BufReader::with_capacity(3, data.as_bytes());
+    let results = parse_reader::<TypedReaderResultsRows<f64>, _>(reader, &params).unwrap();
+
+    assert_eq!(results.num_fields, 3);
+    assert_eq!(results.num_lines, 2);
+    assert_eq!(results.results, vec![1.5, 2.25, -3e2, 4.125, 5.0, 6.5]);
+}
+
+//A field that isn't a valid number returns an error telling us where it lives rather than panicking
+#[test]
+fn load_bytes_f64_conversion_error_test() {
+    let data = "% x y z\n1.0 2.0 3.0\n4.0 1.2.3 6.0\n";
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        ..Default::default()
+    };
+
+    match load_bytes_f64(data.as_bytes(), &params).err().unwrap() {
+        ReaderError::Conversion { row, column, line, offset, bytes, .. } => {
+            assert_eq!(row, 1);
+            assert_eq!(column, 1);
+            assert_eq!(line, 3);
+            assert_eq!(offset, 24);
+            assert_eq!(bytes, b"1.2.3".to_vec());
+        }
+        err => panic!("Unexpected error: {}", err),
+    }
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        usecols: Some(vec![2.into()]),
+        row_format: false,
+        ..Default::default()
+    };
+
+    let data = "% x y z\n1.0 2.0 3.0\n4.0 5.0 6.0\n\n7.0 8.0 abc\n";
+
+    match load_bytes_f64(data.as_bytes(), &params).err().unwrap() {
+        ReaderError::Conversion { row, column, line, offset, bytes, .. } => {
+            assert_eq!(row, 2);
+            assert_eq!(column, 2);
+            assert_eq!(line, 5);
+            assert_eq!(offset, 41);
+            assert_eq!(bytes, b"abc".to_vec());
+        }
+        err => panic!("Unexpected error: {}", err),
+    }
+}
+
+fn load_bytes_min_int(bytes: &[u8], params: &ReaderParams) -> Result<Box<dyn ReaderResults<MinInt>>, ReaderError> {
+    load_text!(@bytes bytes, params, MinInt)
+}
+
+//The FromStr version of the reader also tells us where the bad field lives
+#[test]
+fn load_bytes_custom_conversion_error_test() {
+    let data = "1, 2\n3, x4\n";
+
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        ..Default::default()
+    };
+
+    match load_bytes_min_int(data.as_bytes(), &params).err().unwrap() {
+        ReaderError::Conversion { row, column, line, offset, bytes, .. } => {
+            assert_eq!(row, 1);
+            assert_eq!(column, 1);
+            assert_eq!(line, 2);
+            assert_eq!(offset, 8);
+            assert_eq!(bytes, b"x4".to_vec());
+        }
+        err => panic!("Unexpected error: {}", err),
+    }
+
+    //A field that isn't valid UTF-8 can't be handed off to FromStr
+    let data = b"1, 2\n3, \xff\n";
+
+    match load_bytes_min_int(data, &params).err().unwrap() {
+        ReaderError::Utf8 { row, column, line, bytes, .. } => {
+            assert_eq!(row, 1);
+            assert_eq!(column, 1);
+            assert_eq!(line, 2);
+            assert_eq!(bytes, b"\xff".to_vec());
+        }
+        err => panic!("Unexpected error: {}", err),
+    }
+}
+
+//Callers can tell what went wrong along with where it went wrong in the data
+#[test]
+fn reader_error_test() {
+    let data = "% x y z\n1 2 3\n4 5 6\n7 8\n";
+    let reader = BufReader::with_capacity(4, data.as_bytes());
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        skip_header: Some(1),
+        ..Default::default()
+    };
+
+    match load_reader_i32(reader, &params).err().unwrap() {
+        ReaderError::FieldCountMismatch { line, offset, expected, found } => {
+            assert_eq!(line, 4);
+            assert_eq!(offset, 20);
+            assert_eq!(expected, 3);
+            assert_eq!(found, 2);
+        }
+        err => panic!("Unexpected error: {}", err),
+    }
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        delimiter: Delimiter::Any(b','),
+        ..Default::default()
+    };
+
+    match parse_bytes::<RawReaderResultsRows>(b"1,2\n3,4,\n", &params).err().unwrap() {
+        ReaderError::TrailingDelimiter { line, offset } => {
+            assert_eq!(line, 2);
+            assert_eq!(offset, 4);
+        }
+        err => panic!("Unexpected error: {}", err),
+    }
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        usecols: Some(vec![3.into()]),
+        ..Default::default()
+    };
+
+    match load_bytes_i32(data.as_bytes(), &params).err().unwrap() {
+        ReaderError::InvalidParams { field, .. } => assert_eq!(field, "usecols"),
+        err => panic!("Unexpected error: {}", err),
+    }
+
+    match load_txt_i32("this_file_does_not_exist.txt", &params).err().unwrap() {
+        ReaderError::Io(err) => assert_eq!(err.kind(), std::io::ErrorKind::NotFound),
+        err => panic!("Unexpected error: {}", err),
+    }
+}
+
+//Lines with errors are skipped over and reported back rather than failing the whole read
+#[test]
+fn row_errors_skip_and_report_test() {
+    let data = "# header comment\n1.0,2.0,3.0\n4.0,5.0\n6.0,7.0,8.0,\n9.0,x,10.0\n11.0,12.0,13.0,14.0\n15.0,16.0,17.0 # the end\n";
+
+    for &row_format in &[true, false] {
+        let params = ReaderParams {
+            delimiter: Delimiter::Any(b','),
+            row_format,
+            row_errors: RowErrors::SkipAndReport,
+            ..Default::default()
+        };
+
+        //A tiny buffer makes sure that lines which are split up across buffers are still reported correctly
+        let reader = BufReader::with_capacity(5, data.as_bytes());
+        let results = load_reader_f64(reader, &params).unwrap();
+
+        assert_eq!(results.get_num_lines(), 2);
+        assert_eq!(results.get_num_fields(), 3);
+        assert_eq!(results.get_row(0), vec![1.0, 2.0, 3.0]);
+        assert_eq!(results.get_row(1), vec![15.0, 16.0, 17.0]);
+
+        let rejected = results.get_rejected();
+        assert_eq!(rejected.len(), 4);
+
+        let lines: Vec<usize> = rejected.iter().map(|x| x.line).collect();
+        assert_eq!(lines, vec![3, 4, 5, 6]);
+
+        for rejected_line in rejected.iter() {
+            let bytes = str::from_utf8(&rejected_line.bytes).unwrap();
+            assert_eq!(rejected_line.offset, data.find(bytes).unwrap());
+        }
+        assert_eq!(rejected[0].bytes, b"4.0,5.0".to_vec());
+        assert_eq!(rejected[3].bytes, b"11.0,12.0,13.0,14.0".to_vec());
+
+        match &rejected[0].reason {
+            ReaderError::FieldCountMismatch { expected: 3, found: 2, .. } => {}
+            err => panic!("Unexpected error: {}", err),
+        }
+        match &rejected[1].reason {
+            ReaderError::TrailingDelimiter { line: 4, .. } => {}
+            err => panic!("Unexpected error: {}", err),
+        }
+        match &rejected[2].reason {
+            ReaderError::Conversion { row: 1, column: 1, line: 5, bytes, .. } => assert_eq!(bytes, &b"x".to_vec()),
+            err => panic!("Unexpected error: {}", err),
+        }
+        match &rejected[3].reason {
+            ReaderError::FieldCountMismatch { expected: 3, found: 4, .. } => {}
+            err => panic!("Unexpected error: {}", err),
+        }
+
+        //The raw results are rolled back the same way, but they never see the conversion error
+        let raw_results = parse_bytes::<RawReaderResultsCols>(data.as_bytes(), &params).unwrap();
+        assert_eq!(raw_results.num_lines, 3);
+        assert_eq!(raw_results.results, vec![b"1.09.015.0".to_vec(), b"2.0x16.0".to_vec(), b"3.010.017.0".to_vec()]);
+        assert_eq!(raw_results.rejected.len(), 3);
+    }
+
+    //If we're only skipping lines then nothing gets saved off about them
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        row_errors: RowErrors::Skip,
+        ..Default::default()
+    };
+
+    let results = load_bytes_f64(data.as_bytes(), &params).unwrap();
+
+    assert_eq!(*results.get_results(), vec![1.0, 2.0, 3.0, 15.0, 16.0, 17.0]);
+    assert!(results.get_rejected().is_empty());
+
+    //Bad parameters still fail
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        usecols: Some(vec![5.into()]),
+        row_errors: RowErrors::Skip,
+        ..Default::default()
+    };
+
+    match load_bytes_f64(data.as_bytes(), &params).err().unwrap() {
+        ReaderError::InvalidParams { field: "usecols", .. } => {}
+        err => panic!("Unexpected error: {}", err),
+    }
+}
+
+//If our first line of data is skipped then the next one tells us how many fields there are
+#[test]
+fn row_errors_first_line_test() {
+    let data = "a 1 2 3\n4 5\n6 7\n";
+
+    let params = ReaderParams {
+        row_format: false,
+        row_errors: RowErrors::SkipAndReport,
+        ..Default::default()
+    };
+
+    let results = load_bytes_i32(data.as_bytes(), &params).unwrap();
+
+    assert_eq!(results.get_num_fields(), 2);
+    assert_eq!(*results.get_results(), vec![4, 6, 5, 7]);
+    assert_eq!(results.get_rejected()[0].bytes, b"a 1 2 3".to_vec());
+}
+
+//Column names can come from a header line and be used to pick out our columns
+#[test]
+fn names_header_test() {
+    let data = "% Some data we saved off\ntime x y z temperature % units\n0.0 1.0 2.0 3.0 300.0\n1.0 4.0 5.0 6.0 310.0\n";
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        names: Some(Names::Header),
+        ..Default::default()
+    };
+
+    let results = load_bytes_f64(data.as_bytes(), &params).unwrap();
+
+    assert_eq!(*results.get_names(), vec!["time", "x", "y", "z", "temperature"]);
+    assert_eq!(results.get_num_lines(), 2);
+    assert_eq!(results.get_col_by_name("temperature"), Some(vec![300.0, 310.0]));
+    assert_eq!(results.get_col_by_name("pressure"), None);
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        usecols: Some(vec!["temperature".into(), 0.into()]),
+        names: Some(Names::Header),
+        row_format: false,
+        ..Default::default()
+    };
+
+    let reader = BufReader::with_capacity(5, data.as_bytes());
+    let results = load_reader_f64(reader, &params).unwrap();
+
+    assert_eq!(*results.get_names(), vec!["temperature", "time"]);
+    assert_eq!(*results.get_results(), vec![300.0, 310.0, 0.0, 1.0]);
+
+    //Errors in our data still know where they are after the names are read in
+    let data = "time,x\n0.0,1.0\n1.0,a\n";
+
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        names: Some(Names::Header),
+        ..Default::default()
+    };
+
+    match load_bytes_f64(data.as_bytes(), &params).err().unwrap() {
+        ReaderError::Conversion { row: 1, column: 1, line: 3, offset: 19, .. } => {}
+        err => panic!("Unexpected error: {}", err),
+    }
+}
+
+//Column names can also come from the commented line right before our data
+#[test]
+fn names_comment_test() {
+    let data = "# Some data we saved off\n#time, x , y\n\n1,2,3\n4,5,6\n";
+
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        usecols: Some(vec!["y".into(), "time".into()]),
+        names: Some(Names::Comment),
+        ..Default::default()
+    };
+
+    let results = load_bytes_i32(data.as_bytes(), &params).unwrap();
+
+    //In row major order our columns are in the order they appear in the data
+    assert_eq!(*results.get_names(), vec!["time", "y"]);
+    assert_eq!(*results.get_results(), vec![1, 3, 4, 6]);
+
+    let results = parse_bytes::<RawReaderResultsRows>(data.as_bytes(), &params).unwrap();
+
+    assert_eq!(results.names, vec!["time", "y"]);
+    assert_eq!(results.num_lines, 2);
+
+    //A name that isn't one of our columns
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        usecols: Some(vec!["z".into()]),
+        names: Some(Names::Comment),
+        ..Default::default()
+    };
+
+    match load_bytes_i32(data.as_bytes(), &params).err().unwrap() {
+        ReaderError::InvalidParams { field: "usecols", .. } => {}
+        err => panic!("Unexpected error: {}", err),
+    }
+
+    //There's no comment right before our data
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        names: Some(Names::Comment),
+        ..Default::default()
+    };
+
+    match load_bytes_i32(b"1,2,3\n# x y z\n".as_ref(), &params).err().unwrap() {
+        ReaderError::InvalidParams { field: "names", .. } => {}
+        err => panic!("Unexpected error: {}", err),
+    }
+}
+
+//Quoted fields can have delimiters, comments, escaped quotes, and newlines within them
+#[test]
+fn quoted_fields_test() {
+    let data = "\"last, first\",score\n\"Smith, J\",3.2\n\"O\"\"Brien # 2\",\"4.5\"\n\"multi\r\nline\",1.0 # a comment\n";
+
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        usecols: Some(vec!["last, first".into()]),
+        names: Some(Names::Header),
+        quote: Some(b'"'),
+        ..Default::default()
+    };
+
+    //A tiny buffer makes sure that quoted fields split up across buffers still work
+    for capacity in 1..5 {
+        let reader = BufReader::with_capacity(capacity, data.as_bytes());
+        let results = load_reader_string(reader, &params).unwrap();
+
+        assert_eq!(*results.get_names(), vec!["last, first"]);
+        assert_eq!(*results.get_results(), vec!["Smith, J", "O\"Brien # 2", "multi\r\nline"]);
+    }
+
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        usecols: Some(vec!["score".into()]),
+        names: Some(Names::Header),
+        quote: Some(b'"'),
+        ..Default::default()
+    };
+
+    let results = load_bytes_f64(data.as_bytes(), &params).unwrap();
+
+    assert_eq!(*results.get_results(), vec![3.2, 4.5, 1.0]);
+
+    //Our line numbers still count the newlines within quotes
+    let data = "\"a\nb\",1\n\"c\",x\n";
+
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        usecols: Some(vec![1.into()]),
+        quote: Some(b'"'),
+        ..Default::default()
+    };
+
+    match load_bytes_min_int(data.as_bytes(), &params).err().unwrap() {
+        ReaderError::Conversion { row: 1, column: 1, line: 3, offset: 12, .. } => {}
+        err => panic!("Unexpected error: {}", err),
+    }
+
+    //Without a quote character the quotes are just a part of our fields
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        ..Default::default()
+    };
+
+    let results = load_bytes_string(b"\"a\",\"b\"\n".as_ref(), &params).unwrap();
+
+    assert_eq!(*results.get_results(), vec!["\"a\"", "\"b\""]);
+}
+
+//A quoted field that's never closed is an error rather than taking in the rest of our data
+#[test]
+fn unterminated_quote_test() {
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        quote: Some(b'"'),
+        ..Default::default()
+    };
+
+    match parse_bytes::<RawReaderResultsRows>(b"1,2\n3,\"4\n", &params).err().unwrap() {
+        ReaderError::UnterminatedQuote { line: 2, offset: 6 } => {}
+        err => panic!("Unexpected error: {}", err),
+    }
+
+    match load_bytes_i32(b"1,\"2\n3,4\n", &params).err().unwrap() {
+        ReaderError::UnterminatedQuote { line: 1, offset: 2 } => {}
+        err => panic!("Unexpected error: {}", err),
+    }
+
+    //Skipping over bad lines can't help, since there's no line left after the quote
+    let params = ReaderParams { row_errors: RowErrors::SkipAndReport, ..params };
+    let reader = BufReader::with_capacity(2, b"1,2\n3,\"4\n".as_ref());
+    assert!(load_reader_i32(reader, &params).is_err());
+}
+
+//Missing fields are filled in, and empty fields between delimiters still count as fields
+#[test]
+fn missing_values_test() {
+    let data = "a,b,c\n1,NA,3\n,5,--\n7, nan ,\n";
+
+    let missing = MissingValues {
+        tokens: vec![String::from("NA"), String::from("--")],
+        fill: Some(String::from("-1")),
+        columns: vec![
+            ColumnMissingValues {
+                column: "b".into(),
+                tokens: vec![String::from("nan")],
+                fill: Some(String::from("-2")),
+            },
+            ColumnMissingValues {
+                column: 2.into(),
+                tokens: vec![],
+                fill: Some(String::from("0")),
+            },
+        ],
+    };
+
+    for &row_format in &[true, false] {
+        let params = ReaderParams {
+            delimiter: Delimiter::Any(b','),
+            names: Some(Names::Header),
+            row_format,
+            missing_values: Some(missing.clone()),
+            ..Default::default()
+        };
+
+        let results = load_bytes_f64(data.as_bytes(), &params).unwrap();
+
+        assert_eq!(results.get_num_fields(), 3);
+        assert_eq!(results.get_row(0), vec![1.0, -2.0, 3.0]);
+        assert_eq!(results.get_row(1), vec![-1.0, 5.0, 0.0]);
+        assert_eq!(results.get_row(2), vec![7.0, -2.0, 0.0]);
+
+        let mut filled = results.get_filled().clone();
+        filled.sort_unstable();
+        assert_eq!(filled, vec![(0, 1), (1, 0), (1, 2), (2, 1), (2, 2)]);
+    }
+
+    //The raw results are filled in as well, and only the columns we're using are recorded
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        usecols: Some(vec![2.into(), 0.into()]),
+        names: Some(Names::Header),
+        missing_values: Some(missing.clone()),
+        ..Default::default()
+    };
+
+    let results = parse_bytes::<RawReaderResultsRows>(data.as_bytes(), &params).unwrap();
+
+    assert_eq!(results.results, b"13-1070".to_vec());
+    assert_eq!(results.filled, vec![(1, 0), (1, 1), (2, 1)]);
+
+    //Without a fill value missing fields are left empty
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        skip_header: Some(1),
+        missing_values: Some(MissingValues {
+            tokens: vec![String::from("NA")],
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let results = load_bytes_string(b"a,b,c\n,NA, \n".as_ref(), &params).unwrap();
+
+    assert_eq!(*results.get_results(), vec!["", "", ""]);
+    assert_eq!(*results.get_filled(), vec![(0, 0), (0, 1), (0, 2)]);
+
+    //Missing values that aren't filled in still fail to convert
+    match load_bytes_f64(b"a,b,c\n1,2,3\n4,,6\n".as_ref(), &params).err().unwrap() {
+        ReaderError::Conversion { row: 1, column: 1, line: 3, offset: 14, .. } => {}
+        err => panic!("Unexpected error: {}", err),
+    }
+
+    //A column name that doesn't exist
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        names: Some(Names::Header),
+        missing_values: Some(MissingValues {
+            columns: vec![ColumnMissingValues {
+                column: "d".into(),
+                tokens: vec![],
+                fill: None,
+            }],
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    match load_bytes_f64(data.as_bytes(), &params).err().unwrap() {
+        ReaderError::InvalidParams { field: "missing_values", .. } => {}
+        err => panic!("Unexpected error: {}", err),
+    }
+}
+
+//Missing fields that aren't filled in can be read in as None
+#[test]
+fn option_results_test() {
+    let data = "1,NA,3\n,5,--\n7,8,\n";
+
+    let missing = MissingValues {
+        tokens: vec![String::from("NA"), String::from("--")],
+        columns: vec![ColumnMissingValues {
+            column: 0.into(),
+            tokens: vec![],
+            fill: Some(String::from("0")),
+        }],
+        ..Default::default()
+    };
+
+    for &row_format in &[true, false] {
+        let params = ReaderParams {
+            delimiter: Delimiter::Any(b','),
+            row_format,
+            missing_values: Some(missing.clone()),
+            ..Default::default()
+        };
+
+        let results = load_bytes_opt::<f64>(data.as_bytes(), &params).unwrap();
+
+        assert_eq!(results.get_row(0), vec![Some(1.0), None, Some(3.0)]);
+        assert_eq!(results.get_col(0), vec![Some(1.0), Some(0.0), Some(7.0)]);
+        assert_eq!(results.get_col(2), vec![Some(3.0), None, None]);
+        assert_eq!(results.get_filled().len(), 4);
+    }
+
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        missing_values: Some(missing),
+        ..Default::default()
+    };
+
+    let reader = BufReader::with_capacity(3, data.as_bytes());
+    let results = load_reader_opt::<u8, _>(reader, &params).unwrap();
+
+    assert_eq!(*results.get_results(), vec![Some(1), None, Some(3), Some(0), Some(5), None, Some(7), Some(8), None]);
+
+    let results = load_bytes_opt_min_int(data.as_bytes(), &params).unwrap();
+
+    assert_eq!(results.get_row(1), vec![Some(MinInt { x: 0 }), Some(MinInt { x: -5 }), None]);
+
+    //Fields that aren't missing still need to convert
+    match load_bytes_opt::<f64>(b"1,2\nx,\n".as_ref(), &params).err().unwrap() {
+        ReaderError::Conversion { row: 1, column: 0, .. } => {}
+        err => panic!("Unexpected error: {}", err),
+    }
+}
+
+fn load_bytes_opt_min_int(bytes: &[u8], params: &ReaderParams) -> Result<Box<dyn ReaderResults<Option<MinInt>>>, ReaderError> {
+    load_text!(@option @bytes bytes, params, MinInt)
+}
+
+//Each column can be read in as its own type in a single pass over the data
+#[test]
+fn load_table_test() {
+    let data = "id,temp,flag,label\n1,20.5,true,\"a, b\"\n2,21.0,false,c\n3,x,true,d\n4,22.5,false,e\n";
+
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        names: Some(Names::Header),
+        quote: Some(b'"'),
+        row_errors: RowErrors::SkipAndReport,
+        dtypes: Some(vec![DataType::I64, DataType::F64, DataType::Bool, DataType::String]),
+        ..Default::default()
+    };
+
+    let table = load_bytes_table(data.as_bytes(), &params).unwrap();
+
+    assert_eq!(table.num_lines, 3);
+    assert_eq!(table.num_fields, 4);
+    assert_eq!(table.get_column(0).as_i64(), Some(&[1, 2, 4][..]));
+    assert_eq!(table.get_column(1).as_f64(), Some(&[20.5, 21.0, 22.5][..]));
+    assert_eq!(table.get_column_by_name("flag").unwrap().as_bool(), Some(&[true, false, false][..]));
+    assert_eq!(*table.get_column_by_name("label").unwrap(), ColumnData::String(vec![String::from("a, b"), String::from("c"), String::from("e")]));
+    assert_eq!(table.get_column(1).dtype(), DataType::F64);
+    assert_eq!(table.get_column(1).as_i64(), None);
+    assert_eq!(table.rejected.len(), 1);
+    assert_eq!(table.rejected[0].line, 4);
+
+    //Our types follow usecols
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        names: Some(Names::Header),
+        usecols: Some(vec!["flag".into(), "id".into()]),
+        dtypes: Some(vec![DataType::Bool, DataType::U8]),
+        ..Default::default()
+    };
+
+    let reader = BufReader::with_capacity(4, "id,flag\n1,true\n2,false\n".as_bytes());
+    let table = load_reader_table(reader, &params).unwrap();
+
+    assert_eq!(table.names, vec!["flag", "id"]);
+    assert_eq!(table.columns, vec![ColumnData::Bool(vec![true, false]), ColumnData::U8(vec![1, 2])]);
+
+    //We need a type for every column
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        dtypes: Some(vec![DataType::I64, DataType::F64]),
+        ..Default::default()
+    };
+
+    match load_bytes_table(b"1,2.0\n3,4.0\n".as_ref(), &params) {
+        Ok(table) => assert_eq!(table.columns, vec![ColumnData::I64(vec![1, 3]), ColumnData::F64(vec![2.0, 4.0])]),
+        Err(err) => panic!("Unexpected error: {}", err),
+    }
+    match load_bytes_table(b"1,2.0,3\n".as_ref(), &params).err().unwrap() {
+        ReaderError::InvalidParams { field: "dtypes", .. } => {}
+        err => panic!("Unexpected error: {}", err),
+    }
+    match load_bytes_table(b"1\n".as_ref(), &params).err().unwrap() {
+        ReaderError::InvalidParams { field: "dtypes", .. } => {}
+        err => panic!("Unexpected error: {}", err),
+    }
+    match load_bytes_table(b"1\n".as_ref(), &ReaderParams::default()).err().unwrap() {
+        ReaderError::InvalidParams { field: "dtypes", .. } => {}
+        err => panic!("Unexpected error: {}", err),
+    }
+}
+
+#[cfg(feature = "derive")]
+#[derive(FromRow, Debug, PartialEq)]
+struct Particle {
+    id: u64,
+    #[col(name = "temp", default = 273.15)]
+    temp: f64,
+    #[col(3)]
+    label: String,
+    #[col(2)]
+    flag: Option<bool>,
+}
+
+#[cfg(feature = "derive")]
+#[derive(FromRow, Debug, PartialEq)]
+struct Point(f64, #[col(default)] i32);
+
+//Each row can be read straight into a struct where the fields come from columns given by position, index, or name
+#[cfg(feature = "derive")]
+#[test]
+fn load_records_test() {
+    let data = "id,mass,flag,label,temp\n1,2.5,true,a,300.0\n2,3.5,,b,\n3,4.5,false,c,310.5\n";
+
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        names: Some(Names::Header),
+        missing_values: Some(MissingValues::default()),
+        ..Default::default()
+    };
+
+    let particles = load_bytes_records::<Particle>(data.as_bytes(), &params).unwrap();
+
+    assert_eq!(
+        particles,
+        vec![
+            Particle { id: 1, temp: 300.0, label: String::from("a"), flag: Some(true) },
+            Particle { id: 2, temp: 273.15, label: String::from("b"), flag: None },
+            Particle { id: 3, temp: 310.5, label: String::from("c"), flag: Some(false) },
+        ]
+    );
+
+    //Rows that fail to convert can still be skipped over
+    let params = ReaderParams {
+        quote: Some(b'"'),
+        row_errors: RowErrors::SkipAndReport,
+        ..Default::default()
+    };
+
+    let reader = BufReader::with_capacity(4, "1.5 \"\"\n2.5 x\n3.5 7\n".as_bytes());
+    let points = load_reader_records::<Point, _>(reader, &params).unwrap();
+
+    assert_eq!(points, vec![Point(1.5, 0), Point(3.5, 7)]);
+
+    let params = ReaderParams::default();
+
+    match load_bytes_records::<Point>(b"1.5 2\n2.5 x\n".as_ref(), &params).err().unwrap() {
+        ReaderError::Conversion { row, column, line, offset, .. } => {
+            assert_eq!((row, column, line, offset), (1, 1, 2, 10));
+        }
+        err => panic!("Unexpected error: {}", err),
+    }
+
+    //A column name needs to be in our data
+    match load_bytes_records::<Particle>(b"id mass flag label\n1 2.5 true a\n".as_ref(), &ReaderParams { names: Some(Names::Header), ..Default::default() }).err().unwrap() {
+        ReaderError::InvalidParams { field: "usecols", .. } => {}
+        err => panic!("Unexpected error: {}", err),
+    }
+}
+
+//Duplicate columns would leave some of our fields empty
+#[test]
+fn usecols_duplicate_test() {
+    let params = ReaderParams {
+        usecols: Some(vec![1.into(), 0.into(), 1.into()]),
+        ..Default::default()
+    };
+
+    match load_bytes_f64(b"1 2\n3 4\n".as_ref(), &params).err().unwrap() {
+        ReaderError::InvalidParams { field: "usecols", .. } => {}
+        err => panic!("Unexpected error: {}", err),
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize, Debug, PartialEq)]
+enum Phase {
+    Solid,
+    Liquid,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize, Debug, PartialEq)]
+struct Sample {
+    temp: f64,
+    id: u32,
+    phase: Phase,
+    note: Option<String>,
+}
+
+//Rows can be deserialized into anything that supports serde, where the column names are the keys
+#[cfg(feature = "serde")]
+#[test]
+fn load_serde_test() {
+    use std::collections::HashMap;
+
+    let data = "id,temp,phase,note\n1,250.5,Solid,cold\n2,300.0,Liquid,\n";
+
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        names: Some(Names::Header),
+        missing_values: Some(MissingValues::default()),
+        ..Default::default()
+    };
+
+    let samples: Vec<Sample> = load_bytes_serde(data.as_bytes(), &params).unwrap();
+
+    assert_eq!(
+        samples,
+        vec![
+            Sample { temp: 250.5, id: 1, phase: Phase::Solid, note: Some(String::from("cold")) },
+            Sample { temp: 300.0, id: 2, phase: Phase::Liquid, note: None },
+        ]
+    );
+
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        names: Some(Names::Header),
+        usecols: Some(vec!["temp".into(), "id".into()]),
+        missing_values: Some(MissingValues::default()),
+        ..Default::default()
+    };
+
+    let reader = BufReader::with_capacity(4, data.as_bytes());
+    let maps: Vec<HashMap<String, f64>> = load_reader_serde(reader, &params).unwrap();
+
+    assert_eq!(maps.len(), 2);
+    assert_eq!(maps[1]["temp"], 300.0);
+    assert_eq!(maps[1]["id"], 2.0);
+
+    //Strings can be borrowed from our results
+    let results = parse_bytes::<RawReaderResultsRows>(b"1 a\n2 b\n".as_ref(), &ReaderParams::default()).unwrap();
+    let row: (u8, &str) = results.deserialize_row(1).unwrap();
+
+    assert_eq!(row, (2, "b"));
+
+    //Errors tell us which row and column couldn't be deserialized
+    match results.deserialize::<(u8, u8)>().err().unwrap() {
+        ReaderError::Deserialize { row, column, .. } => assert_eq!((row, column), (0, Some(1))),
+        err => panic!("Unexpected error: {}", err),
+    }
+    match results.deserialize::<HashMap<String, String>>().err().unwrap() {
+        ReaderError::Deserialize { row, column, .. } => assert_eq!((row, column), (0, None)),
+        err => panic!("Unexpected error: {}", err),
+    }
+}
+
+//Rows are read in one at a time, and a row with an error doesn't stop us from reading the rest of them
+#[test]
+fn row_iter_test() {
+    let data = "# header comment\nid,x,y,z\n1,2.0,3.0,4.0 # first\n2,5.0\n# comment\n3,x,6.0,7.0\n4,8.0,9.0,10.0\n5,11.0,12.0,13.0";
+
+    let params = ReaderParams {
+        comments: Some(b'#'),
+        delimiter: Delimiter::Any(b','),
+        names: Some(Names::Header),
+        usecols: Some(vec!["z".into(), 1.into()]),
+        ..Default::default()
+    };
+
+    //A tiny buffer makes sure that rows which are split up across buffers are still read in correctly
+    let reader = BufReader::with_capacity(5, data.as_bytes());
+    let mut rows: RowIter<f64, _> = RowIter::new(reader, &params).unwrap();
+
+    assert_eq!(rows.names(), ["x", "z"]);
+    assert_eq!(rows.next().unwrap().unwrap(), vec![2.0, 4.0]);
+    match rows.next().unwrap().err().unwrap() {
+        ReaderError::FieldCountMismatch { line: 4, expected: 4, found: 2, .. } => {}
+        err => panic!("Unexpected error: {}", err),
+    }
+    match rows.next().unwrap().err().unwrap() {
+        ReaderError::Conversion { row: 1, column: 1, line: 6, bytes, .. } => assert_eq!(bytes, b"x".to_vec()),
+        err => panic!("Unexpected error: {}", err),
+    }
+    assert_eq!(rows.next().unwrap().unwrap(), vec![8.0, 10.0]);
+    assert_eq!(rows.next().unwrap().unwrap(), vec![11.0, 13.0]);
+    assert!(rows.next().is_none());
+    assert!(rows.next().is_none());
+
+    //Lines with errors can also be skipped over without telling us about them
+    let params = ReaderParams {
+        comments: Some(b'#'),
+        delimiter: Delimiter::Any(b','),
+        skip_header: Some(1),
+        row_errors: RowErrors::Skip,
+        max_rows: Some(2),
+        ..Default::default()
+    };
+
+    let rows: Vec<Vec<f64>> = RowIter::<f64, _>::new(data.as_bytes(), &params).unwrap().collect::<Result<_, _>>().unwrap();
+
+    assert_eq!(rows, vec![vec![1.0, 2.0, 3.0, 4.0], vec![4.0, 8.0, 9.0, 10.0]]);
+
+    //Types that support FromStr can be read in as well
+    let params = ReaderParams {
+        delimiter: Delimiter::WhiteSpace,
+        skip_footer: Some(1),
+        ..Default::default()
+    };
+
+    let rows: Vec<Vec<String>> = RowIter::<String, _, FromStrParse>::new(b"a b\nc d\ntotal 2\n".as_ref(), &params)
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(rows, vec![vec!["a", "b"], vec!["c", "d"]]);
+
+    //Errors that aren't from a single row end our iteration
+    let params = ReaderParams {
+        skip_header: Some(5),
+        ..Default::default()
+    };
+
+    let mut rows = RowIter::<i32, _>::new(b"1 2\n3 4\n".as_ref(), &params).unwrap();
+
+    assert!(rows.next().unwrap().is_err());
+    assert!(rows.next().is_none());
+
+    let file_rows: Vec<Vec<i32>> = RowIter::<i32, _>::from_file("int_testv3.txt", &ReaderParams {
+        comments: Some(b'%'),
+        skip_footer: Some(1),
+        ..Default::default()
+    })
+    .unwrap()
+    .collect::<Result<_, _>>()
+    .unwrap();
+
+    assert_eq!(file_rows.concat(), vec![1, 2, 3, 4, 5, 6, 10, 11, 12, 13, 14, 15, 19, 20, 21, 25, 26, 27]);
+}
+
+//Our data is read in a few rows at a time, where each batch has the same layout that load_txt_* would give us
+#[test]
+fn batches_test() {
+    let data = "a b c\n1 2 3\n4 5 6\n7 8\n9 10 11\n12 13 14 # comment\n15 16 17\n18 19 20\n";
+
+    for &row_format in &[true, false] {
+        let params = ReaderParams {
+            comments: Some(b'#'),
+            names: Some(Names::Header),
+            usecols: Some(vec!["c".into(), "a".into()]),
+            row_errors: RowErrors::SkipAndReport,
+            row_format,
+            ..Default::default()
+        };
+
+        let reader = BufReader::with_capacity(5, data.as_bytes());
+        let batches: Vec<_> = Batches::<i32, _>::new(reader, &params, 3).unwrap().collect::<Result<_, _>>().unwrap();
+
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].get_num_lines(), 3);
+        assert_eq!(batches[0].get_num_fields(), 2);
+        assert_eq!(batches[0].get_col_by_name("c"), Some(vec![3, 6, 11]));
+        assert_eq!(batches[1].get_col_by_name("a"), Some(vec![12, 15, 18]));
+
+        let rejected = batches[0].get_rejected();
+        assert_eq!(rejected.len(), 1);
+        assert_eq!(rejected[0].line, 4);
+        assert!(batches[1].get_rejected().is_empty());
+    }
+
+    //The same results can be reused for every batch
+    let params = ReaderParams {
+        skip_header: Some(1),
+        row_errors: RowErrors::Skip,
+        comments: Some(b'#'),
+        max_rows: Some(5),
+        ..Default::default()
+    };
+
+    let mut batches = Batches::<f64, _>::new(data.as_bytes(), &params, 2).unwrap();
+    let mut batch = ReaderResultsRow { num_fields: 0, num_lines: 0, results: Vec::with_capacity(6), rejected: Vec::new(), names: Vec::new(), filled: Vec::new() };
+    let ptr = batch.results.as_ptr();
+    let mut results = Vec::new();
+
+    while batches.read_rows(&mut batch).unwrap() {
+        assert_eq!(batch.results.as_ptr(), ptr);
+        assert_eq!(batch.num_fields, 3);
+        results.push(batch.num_lines);
+    }
+
+    assert_eq!(results, vec![2, 2, 1]);
+    assert_eq!(batch.num_lines, 0);
+    assert!(!batches.read_rows(&mut batch).unwrap());
+
+    let mut batches = Batches::<f64, _>::new(data.as_bytes(), &params, 4).unwrap();
+    let mut batch = ReaderResultsCol { num_fields: 0, num_lines: 0, results: Vec::new(), rejected: Vec::new(), names: Vec::new(), filled: Vec::new() };
+
+    assert!(batches.read_cols(&mut batch).unwrap());
+    assert_eq!(batch.results, vec![1.0, 4.0, 9.0, 12.0, 2.0, 5.0, 10.0, 13.0, 3.0, 6.0, 11.0, 14.0]);
+    assert!(batches.read_cols(&mut batch).unwrap());
+    assert_eq!(batch.results, vec![15.0, 16.0, 17.0]);
+    assert!(!batches.read_cols(&mut batch).unwrap());
+
+    //Lines with errors stop us when we aren't skipping over them
+    let params = ReaderParams {
+        skip_header: Some(1),
+        comments: Some(b'#'),
+        ..Default::default()
+    };
+
+    let mut batches = Batches::<f64, _>::new(data.as_bytes(), &params, 1).unwrap();
+
+    assert_eq!(batches.next().unwrap().unwrap().get_row(0), vec![1.0, 2.0, 3.0]);
+    assert_eq!(batches.next().unwrap().unwrap().get_row(0), vec![4.0, 5.0, 6.0]);
+    match batches.next().unwrap().err().unwrap() {
+        ReaderError::FieldCountMismatch { line: 4, .. } => {}
+        err => panic!("Unexpected error: {}", err),
+    }
+    assert!(batches.next().is_none());
+
+    assert!(Batches::<f64, _>::new(data.as_bytes(), &params, 0).is_err());
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        skip_footer: Some(1),
+        ..Default::default()
+    };
+
+    let batches: Vec<_> = Batches::<i32, _>::from_file("int_testv3.txt", &params, 4).unwrap().collect::<Result<_, _>>().unwrap();
+    let results: Vec<i32> = batches.iter().flat_map(|x| x.get_results().clone()).collect();
+
+    assert_eq!(batches.iter().map(|x| x.get_num_lines()).collect::<Vec<_>>(), vec![4, 2]);
+    assert_eq!(results, vec![1, 2, 3, 4, 5, 6, 10, 11, 12, 13, 14, 15, 19, 20, 21, 25, 26, 27]);
+}
+
+//The layout of our data can be worked out from the first few lines of it
+#[test]
+fn sniff_test() {
+    let data = "id,name,temp\n1,New York,3.5\n2,Los Angeles,4.0\n";
+    let dialect = sniff_dialect(&mut data.as_bytes(), SNIFF_LINES).unwrap();
+
+    assert_eq!(
+        dialect,
+        Dialect { delimiter: Delimiter::Any(b','), comments: None, names: Some(Names::Header), num_fields: 3 }
+    );
+
+    let data = "% x y z\n1 2 3\n\n4 5 6 % the end\n";
+    let dialect = sniff_dialect(&mut data.as_bytes(), SNIFF_LINES).unwrap();
+
+    assert_eq!(
+        dialect,
+        Dialect { delimiter: Delimiter::WhiteSpace, comments: Some(b'%'), names: Some(Names::Comment), num_fields: 3 }
+    );
+
+    let results = load_bytes_i32(data.as_bytes(), &dialect.params()).unwrap();
+
+    assert_eq!(*results.get_names(), vec!["x", "y", "z"]);
+    assert_eq!(*results.get_results(), vec![1, 2, 3, 4, 5, 6]);
+
+    //A comment that doesn't look like the names of our columns is just a comment
+    let params = sniff_bytes(b"# generated\r\n1.5\t2\r\n3\t4\r\n".as_ref()).unwrap();
+
+    assert_eq!(params.delimiter, Delimiter::Any(b'\t'));
+    assert_eq!(params.comments, Some(b'#'));
+    assert_eq!(params.names, None);
+
+    //Only the lines that we look at are read in
+    let mut reader = "a;b\n1;2\n3;4\n5 6\n".as_bytes();
+    let dialect = sniff_dialect(&mut reader, 3).unwrap();
+
+    assert_eq!(dialect.delimiter, Delimiter::Any(b';'));
+    assert_eq!(dialect.names, Some(Names::Header));
+    assert_eq!(reader, b"5 6\n");
+
+    assert_eq!(sniff_dialect(&mut "".as_bytes(), SNIFF_LINES).unwrap().num_fields, 0);
+
+    let params = sniff("int_testv3.txt").unwrap();
+    let expected = ReaderParams {
+        comments: Some(b'%'),
+        ..Default::default()
+    };
+
+    assert_eq!(params.delimiter, Delimiter::WhiteSpace);
+    assert_eq!(params.comments, Some(b'%'));
+    assert_eq!(load_txt_i32("int_testv3.txt", &params).unwrap().get_results(), load_txt_i32("int_testv3.txt", &expected).unwrap().get_results());
+}
+
+//Each column gets the narrowest type that all of its fields can be read in as
+#[test]
+fn infer_schema_test() {
+    let data = "id,flag,x,label,mixed,empty\n1,true,2,a,true,NA\n2,false,3.5,b,1,\n3,true,,4,false,NA\n4,oops\n5,false,1e3,c,0,NA\n";
+
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        names: Some(Names::Header),
+        missing_values: Some(MissingValues::default()),
+        row_errors: RowErrors::SkipAndReport,
+        ..Default::default()
+    };
+
+    let schema = infer_schema_bytes(data.as_bytes(), &params).unwrap();
+
+    assert_eq!(schema.num_lines, 4);
+    assert_eq!(schema.rejected.len(), 1);
+    assert_eq!(schema.dtypes(), vec![DataType::I64, DataType::Bool, DataType::F64, DataType::String, DataType::String, DataType::String]);
+    assert_eq!(schema.get_column_by_name("x"), Some(&ColumnSchema { dtype: DataType::F64, nullable: true }));
+    assert_eq!(schema.get_column_by_name("empty"), Some(&ColumnSchema { dtype: DataType::String, nullable: true }));
+    assert!(!schema.columns[0].nullable);
+
+    //Only the first rows are looked at when we use a sample, and our columns follow usecols
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        names: Some(Names::Header),
+        usecols: Some(vec!["label".into(), "id".into(), "x".into()]),
+        missing_values: Some(MissingValues::default()),
+        max_rows: Some(2),
+        ..Default::default()
+    };
+
+    let reader = BufReader::with_capacity(6, data.as_bytes());
+    let schema = infer_schema_reader(reader, &params).unwrap();
+
+    assert_eq!(schema.names, vec!["label", "id", "x"]);
+    assert_eq!(schema.dtypes(), vec![DataType::String, DataType::I64, DataType::F64]);
+
+    //Our schema can be used to read in the data
+    let params = ReaderParams {
+        dtypes: Some(schema.dtypes()),
+        ..params
+    };
+    let table = load_bytes_table(data.as_bytes(), &params).unwrap();
+
+    assert_eq!(table.get_column_by_name("x").unwrap().as_f64(), Some(&[2.0, 3.5][..]));
+
+    let schema = infer_schema("float_testv1.txt", &ReaderParams::default()).unwrap();
+
+    assert_eq!(schema.dtypes(), vec![DataType::F64; 3]);
+}
+
+//Our results become an Array2 in the same layout that they were read in as
+#[cfg(feature = "ndarray")]
+#[test]
+fn load_array_test() {
+    let data = "1 2 3\n4 5 6\n";
+
+    let results = parse_bytes::<TypedReaderResultsRows<f64>>(data.as_bytes(), &ReaderParams::default()).unwrap();
+    let results = ReaderResultsRow::from(results);
+    let ptr = results.results.as_ptr();
+    let array = results.into_array2().unwrap();
+
+    assert_eq!(array.shape(), &[2, 3]);
+    assert_eq!(array[[1, 0]], 4.0);
+    assert!(array.is_standard_layout());
+    assert_eq!(array.as_ptr(), ptr);
+
+    let params = ReaderParams {
+        row_format: false,
+        ..Default::default()
+    };
+
+    let results = parse_bytes::<TypedReaderResultsCols<i32>>(data.as_bytes(), &params).unwrap();
+    let results = ReaderResultsCol::from(results);
+    let ptr = results.results.as_ptr();
+    let array = results.into_array2().unwrap();
+
+    assert_eq!(array.shape(), &[2, 3]);
+    assert_eq!(array[[1, 0]], 4);
+    assert!(array.t().is_standard_layout());
+    assert_eq!(array.as_ptr(), ptr);
+
+    let array = load_bytes_array::<u8>(data.as_bytes(), &params).unwrap();
+
+    assert_eq!(array.column(2).to_vec(), vec![3, 6]);
+    assert!(array.t().is_standard_layout());
+
+    let reader = BufReader::with_capacity(4, data.as_bytes());
+    let array = load_reader_array::<f32, _>(reader, &ReaderParams::default()).unwrap();
+
+    assert_eq!(array.row(1).to_vec(), vec![4.0, 5.0, 6.0]);
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        skip_footer: Some(1),
+        ..Default::default()
+    };
+    let array = load_txt_array::<i64>("int_testv3.txt", &params).unwrap();
+
+    assert_eq!(array.shape(), &[6, 3]);
+    assert_eq!(array[[5, 2]], 27);
+
+    let results = ReaderResultsRow { num_fields: 2, num_lines: 2, results: vec![1.0], rejected: Vec::new(), names: Vec::new(), filled: Vec::new() };
+
+    assert!(results.into_array2().is_err());
+}
+
+//A Fortran ordered array is filled in directly while parsing, whether the lines were counted up front or its columns had to grow
+#[cfg(feature = "ndarray")]
+#[test]
+fn load_array_fortran_test() {
+    let mut data = String::from("# header\nskipped line\na b c\n");
+    for i in 0..200 {
+        data.push_str(&format!("{} {} {}\n\n", i, 2 * i, 3 * i));
+        if i % 50 == 0 {
+            data.push_str("1 x 3\n# comment\n");
+        }
+    }
+    data.push_str("footer line\n");
+
+    let c_params = ReaderParams {
+        comments: Some(b'#'),
+        skip_header: Some(1),
+        skip_footer: Some(1),
+        names: Some(Names::Header),
+        row_errors: RowErrors::Skip,
+        ..Default::default()
+    };
+    let f_params = ReaderParams {
+        row_format: false,
+        ..c_params.clone()
+    };
+    let expected = load_bytes_array::<i64>(data.as_bytes(), &c_params).unwrap();
+
+    assert_eq!(expected.shape(), &[200, 3]);
+    assert_eq!(expected.row(199).to_vec(), vec![199, 398, 597]);
+
+    let array = load_bytes_array::<i64>(data.as_bytes(), &f_params).unwrap();
+
+    assert!(array.t().is_standard_layout());
+    assert_eq!(array, expected);
+
+    let reader = BufReader::with_capacity(8, data.as_bytes());
+    let array = load_reader_array::<i64, _>(reader, &f_params).unwrap();
+
+    assert!(array.t().is_standard_layout());
+    assert_eq!(array, expected);
+
+    let file = std::env::temp_dir().join("data_reader_load_array_fortran_test.txt");
+    std::fs::write(&file, &data).unwrap();
+    let array = load_txt_array::<i64>(file.to_str().unwrap(), &f_params);
+    std::fs::remove_file(file).unwrap();
+    let array = array.unwrap();
+
+    assert!(array.t().is_standard_layout());
+    assert_eq!(array, expected);
+
+    let params = ReaderParams {
+        max_rows: Some(70),
+        ..f_params.clone()
+    };
+    let array = load_bytes_array::<i64>(data.as_bytes(), &params).unwrap();
+
+    assert_eq!(array.shape(), &[70, 3]);
+    assert_eq!(array.column(2).to_vec(), (0..70).map(|x| 3 * x).collect::<Vec<i64>>());
+
+    let params = ReaderParams {
+        row_format: false,
+        ..Default::default()
+    };
+    let array = load_bytes_array::<i64>(b"# only comments\n", &params);
+
+    assert!(matches!(array, Err(ReaderError::NoData)));
+}
+
+//Column major results are already laid out the way a DMatrix is stored
+#[cfg(feature = "nalgebra")]
+#[test]
+fn into_dmatrix_test() {
+    let data = "1 2 3\n4 5 6\n";
+    let params = ReaderParams {
+        row_format: false,
+        ..Default::default()
+    };
+
+    let results = ReaderResultsCol::from(parse_bytes::<TypedReaderResultsCols<f64>>(data.as_bytes(), &params).unwrap());
+    let ptr = results.results.as_ptr();
+    let matrix = results.into_dmatrix().unwrap();
+
+    assert_eq!(matrix.shape(), (2, 3));
+    assert_eq!(matrix[(1, 0)], 4.0);
+    assert_eq!(matrix.as_ptr(), ptr);
+
+    let results = ReaderResultsRow::from(parse_bytes::<TypedReaderResultsRows<f64>>(data.as_bytes(), &ReaderParams::default()).unwrap());
+    let transposed = results.into_dmatrix().unwrap();
+
+    assert_eq!(transposed, matrix);
+    assert_eq!(transposed.row(0).iter().copied().collect::<Vec<_>>(), vec![1.0, 2.0, 3.0]);
+
+    //Results that don't have a value for every field are an error rather than a panic
+    let mut results = ReaderResultsCol::from(parse_bytes::<TypedReaderResultsCols<f64>>(data.as_bytes(), &params).unwrap());
+    results.num_lines = 3;
+    assert!(results.into_dmatrix().is_err());
+}
+
+//Columns from several loads can go into one RecordBatch, and neither numbers nor raw fields are copied
+#[cfg(feature = "arrow")]
+#[test]
+fn into_record_batch_test() {
+    let data = "id,temp,label\n1,300.5,ab\n2,,c\n3,310.0,def\n";
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        names: Some(Names::Header),
+        missing_values: Some(MissingValues::default()),
+        row_format: false,
+        ..Default::default()
+    };
+
+    let ids: TypedReaderResultsCols<u32> = parse_bytes(data.as_bytes(), &ReaderParams { usecols: Some(vec![0.into()]), ..params.clone() }).unwrap();
+    let ids = ReaderResultsCol::from(ids);
+    let ptr = ids.results.as_ptr();
+    let temps: TypedReaderResultsCols<Option<f64>, OptionParse> = parse_bytes(data.as_bytes(), &ReaderParams { usecols: Some(vec![1.into()]), ..params.clone() }).unwrap();
+    let temps = ReaderResultsCol::from(temps);
+    let labels: RawReaderResultsCols = parse_bytes(data.as_bytes(), &ReaderParams { usecols: Some(vec![2.into()]), ..params.clone() }).unwrap();
+    let values_ptr = labels.results[0].as_ptr();
+
+    let mut columns = ArrowColumns::new();
+    columns.push(ids);
+    columns.push(temps);
+    columns.push_raw(labels).unwrap();
+    let batch = columns.into_record_batch(None).unwrap();
+
+    assert_eq!(batch.num_rows(), 3);
+    let schema = batch.schema();
+    let fields: Vec<(String, String, bool)> = schema.fields().iter().map(|x| (x.name().clone(), x.data_type().to_string(), x.is_nullable())).collect();
+    assert_eq!(
+        fields,
+        vec![
+            (String::from("id"), String::from("UInt32"), false),
+            (String::from("temp"), String::from("Float64"), true),
+            (String::from("label"), String::from("Utf8"), false),
+        ]
+    );
+    assert_eq!(batch.column(0).to_data().buffers()[0].as_ptr(), ptr as *const u8);
+    let label_data = batch.column(2).to_data();
+    assert_eq!(label_data.buffers()[1].as_ptr(), values_ptr);
+    assert_eq!(&label_data.buffers()[1][..], b"abcdef");
+
+    //User supplied names need to cover every column
+    let results: TypedReaderResultsCols<f64> = parse_bytes("1 2\n3 4\n".as_bytes(), &ReaderParams { row_format: false, ..Default::default() }).unwrap();
+    let results = ReaderResultsCol::from(results);
+    assert!(results.clone().into_record_batch(Some(vec![String::from("x")])).is_err());
+    let batch = results.into_record_batch(Some(vec![String::from("x"), String::from("y")])).unwrap();
+    assert_eq!(batch.schema().field(1).name(), "y");
+    assert_eq!(batch.column(1).len(), 2);
+}
+
+//Whitespace delimited data with comments and a footer goes straight into a DataFrame
+#[cfg(feature = "polars")]
+#[test]
+fn load_dataframe_test() {
+    let data = "% id temp flag label\n1 300.5 true a\n% comment\n2 1.0e2 false bc\n3 2.5e-1 true d\nend of data\n";
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        names: Some(Names::Comment),
+        skip_footer: Some(1),
+        ..Default::default()
+    };
+
+    let df = load_dataframe_bytes(data.as_bytes(), &params).unwrap();
+
+    assert_eq!(df.shape(), (3, 4));
+    let dtypes: Vec<String> = df.dtypes().iter().map(|x| x.to_string()).collect();
+    assert_eq!(dtypes, vec!["i64", "f64", "bool", "str"]);
+    assert_eq!(df.column("temp").unwrap().f64().unwrap().get(1), Some(100.0));
+    assert_eq!(df.column("label").unwrap().str().unwrap().get(1), Some("bc"));
+
+    //The types can be given instead, and missing fields become nulls
+    let data = "id,count\n1,5\n2,\n3,7\n";
+    let params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        names: Some(Names::Header),
+        missing_values: Some(MissingValues::default()),
+        dtypes: Some(vec![DataType::U8, DataType::I32]),
+        ..Default::default()
+    };
+
+    let df = load_dataframe_reader(BufReader::new(data.as_bytes()), &params).unwrap();
+    let count = df.column("count").unwrap().i32().unwrap();
+    assert_eq!(df.column("id").unwrap().dtype().to_string(), "u8");
+    assert_eq!(count.null_count(), 1);
+    assert_eq!(count.get(2), Some(7));
+
+    let params = ReaderParams { dtypes: Some(vec![DataType::U8]), ..params };
+    assert!(load_dataframe_bytes(data.as_bytes(), &params).is_err());
+}
+
+//Results that are written out are read back in as the same values with the same parameters
+#[test]
+fn save_txt_test() {
+    use data_reader::writer::*;
+
+    let data = "# temperatures\nx,y,z\n1.5,-2.25,3e10\n0.1,5,-0.000001\n";
+    let read_params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        names: Some(Names::Header),
+        ..Default::default()
+    };
+    let results = load_bytes_f64(data.as_bytes(), &read_params).unwrap();
+
+    let file = std::env::temp_dir().join("data_reader_save_txt_test.txt");
+    let file = file.to_str().unwrap();
+    let params = WriterParams {
+        delimiter: Delimiter::Any(b','),
+        header: Some(String::from("temperatures\nin kelvin")),
+        footer: Some(String::from("end")),
+        names: true,
+        ..Default::default()
+    };
+    save_txt(file, results.as_ref(), &params).unwrap();
+
+    let text = std::fs::read_to_string(file).unwrap();
+    assert_eq!(text, "# temperatures\n# in kelvin\nx,y,z\n1.5,-2.25,30000000000\n0.1,5,-0.000001\n# end\n");
+
+    let saved = load_txt_f64(file, &read_params).unwrap();
+    std::fs::remove_file(file).unwrap();
+    assert_eq!(saved.get_results(), results.get_results());
+    assert_eq!(saved.get_names(), results.get_names());
+}
+
+//Each column can have its own printf-style format
+#[test]
+fn save_writer_formats_test() {
+    use data_reader::writer::*;
+
+    let results = load_bytes_f64("1234.5678 -0.5 7.9 0.0001\n-1e-10 2 -3.2 123456789\n".as_bytes(), &ReaderParams::default()).unwrap();
+    let params = WriterParams {
+        formats: Some(vec![String::from("%.6e"), String::from("%10.4f"), String::from("%+d"), String::from("%g")]),
+        newline: String::from("\r\n"),
+        ..Default::default()
+    };
+    let mut out = Vec::<u8>::new();
+    save_writer(&mut out, results.as_ref(), &params).unwrap();
+
+    assert_eq!(str::from_utf8(&out).unwrap(), "1.234568e+03    -0.5000 +7 0.0001\r\n-1.000000e-10     2.0000 -3 1.23457e+08\r\n");
+
+    let params = WriterParams { formats: Some(vec![String::from("%.2f"), String::from("%.2f")]), ..params };
+    assert!(save_writer(&mut out, results.as_ref(), &params).is_err());
+
+    //Missing fields and fields that contain the delimiter can still be read back in
+    let data = "a,1\n\"b,c\",\n";
+    let read_params = ReaderParams {
+        delimiter: Delimiter::Any(b','),
+        quote: Some(b'"'),
+        missing_values: Some(MissingValues { tokens: vec![String::from("NA")], ..Default::default() }),
+        ..Default::default()
+    };
+    let labels = load_bytes_string(data.as_bytes(), &ReaderParams { usecols: Some(vec![0.into()]), ..read_params.clone() }).unwrap();
+    let counts = load_bytes_opt::<i32>(data.as_bytes(), &ReaderParams { usecols: Some(vec![1.into()]), ..read_params.clone() }).unwrap();
+    let params = WriterParams {
+        delimiter: Delimiter::Any(b','),
+        quote: Some(b'"'),
+        missing: String::from("NA"),
+        formats: Some(vec![String::from("%03d")]),
+        ..Default::default()
+    };
+
+    let mut out = Vec::<u8>::new();
+    save_writer(&mut out, labels.as_ref(), &WriterParams { formats: Some(vec![String::from("%s")]), ..params.clone() }).unwrap();
+    assert_eq!(str::from_utf8(&out).unwrap(), "a\n\"b,c\"\n");
+    assert!(save_writer(&mut Vec::<u8>::new(), labels.as_ref(), &params).is_err());
+
+    //Fields that need to be quoted can't be written out without a quote character
+    let params_unquoted = WriterParams { quote: None, formats: None, ..params.clone() };
+    assert!(save_writer(&mut Vec::<u8>::new(), labels.as_ref(), &params_unquoted).is_err());
+    let text = load_bytes_string("a # b\n".as_bytes(), &ReaderParams { comments: None, ..Default::default() }).unwrap();
+    let params_space = WriterParams { quote: None, ..Default::default() };
+    assert!(save_writer(&mut Vec::<u8>::new(), text.as_ref(), &params_space).is_err());
+    let empty = load_bytes_string("a,\n".as_bytes(), &read_params).unwrap();
+    assert!(save_writer(&mut Vec::<u8>::new(), empty.as_ref(), &params_space).is_err());
+    let mut out = Vec::<u8>::new();
+    save_writer(&mut out, empty.as_ref(), &WriterParams { quote: Some(b'"'), ..params_space }).unwrap();
+    assert_eq!(str::from_utf8(&out).unwrap(), "a \"\"\n");
+    let saved = load_bytes_string(&out, &ReaderParams { quote: Some(b'"'), ..Default::default() }).unwrap();
+    assert_eq!(saved.get_results(), empty.get_results());
+
+    let mut out = Vec::<u8>::new();
+    save_writer(&mut out, counts.as_ref(), &params).unwrap();
+    assert_eq!(str::from_utf8(&out).unwrap(), "001\nNA\n");
+    let saved = load_bytes_opt::<i32>(&out, &read_params).unwrap();
+    assert_eq!(saved.get_results(), counts.get_results());
+
+    //Padding from a width isn't a reason to quote a field, so padded numbers are still read back in
+    let results = load_bytes_f64("1.5 -2.25\n3 4\n".as_bytes(), &ReaderParams::default()).unwrap();
+    let params = WriterParams {
+        quote: Some(b'"'),
+        formats: Some(vec![String::from("%10.4f")]),
+        ..Default::default()
+    };
+    let mut out = Vec::<u8>::new();
+    save_writer(&mut out, results.as_ref(), &params).unwrap();
+    assert_eq!(str::from_utf8(&out).unwrap(), "    1.5000    -2.2500\n    3.0000     4.0000\n");
+    let saved = load_bytes_f64(&out, &ReaderParams { quote: Some(b'"'), ..Default::default() }).unwrap();
+    assert_eq!(saved.get_results(), results.get_results());
+}
+
+//A reader that can't seek, where the footer wouldn't parse as data if it made it to the parser.
+#[test]
+fn load_reader_i32_footer_test() {
+    let data = "1 2 3\n4 5 6\n% comment\n7 8 9\nsum: 45\n% end\nrows: 3\n";
+    let reader = BufReader::with_capacity(8, data.as_bytes());
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        skip_footer: Some(2),
+        max_rows: Some(5),
+        ..Default::default()
+    };
+
+    let results = load_reader_i32(reader, &params).unwrap();
+
+    assert_eq!(*results.get_results(), vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        skip_footer: Some(2),
+        max_rows: Some(2),
+        row_format: false,
+        ..Default::default()
+    };
+
+    let results = load_bytes_i32(data.as_bytes(), &params).unwrap();
+
+    assert_eq!(*results.get_results(), vec![1, 4, 2, 5, 3, 6]);
+
+    //There aren't enough lines in the data to skip
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        skip_header: Some(2),
+        skip_footer: Some(3),
+        ..Default::default()
+    };
+
+    assert!(load_bytes_i32(data.as_bytes(), &params).is_err());
+}
+
+//Lines that only end with a carriage return still count towards the footer and the names
+#[test]
+fn carriage_return_footer_test() {
+    let data = "1 2\r3 4\r5 6\r# c\r7 8\r";
+    let params = ReaderParams {
+        skip_footer: Some(1),
+        ..Default::default()
+    };
+
+    let results = load_bytes_i32(data.as_bytes(), &params).unwrap();
+    assert_eq!(*results.get_results(), vec![1, 2, 3, 4, 5, 6]);
+
+    let reader = BufReader::with_capacity(3, data.as_bytes());
+    let results = load_reader_i32(reader, &params).unwrap();
+    assert_eq!(*results.get_results(), vec![1, 2, 3, 4, 5, 6]);
+
+    let rows: Vec<Vec<i32>> = RowIter::<i32, _>::new(data.as_bytes(), &params).unwrap().map(|x| x.unwrap()).collect();
+    assert_eq!(rows, vec![vec![1, 2], vec![3, 4], vec![5, 6]]);
+
+    let params = ReaderParams {
+        names: Some(Names::Header),
+        ..params
+    };
+    let reader = BufReader::with_capacity(3, "x y\r1 2\r3 4\r5 6\r".as_bytes());
+    let results = load_reader_i32(reader, &params).unwrap();
+    assert_eq!(*results.get_names(), vec!["x", "y"]);
+    assert_eq!(*results.get_results(), vec![1, 2, 3, 4]);
+}
+
+//A "\r\n" only ends a single line, so our line numbers are the same as they'd be with "\n"
+#[test]
+fn crlf_line_number_test() {
+    let data = "skip me\r\nx y z\r\n# c\r\n1 2 3\r\n4 5 6\r\n7 8\r\n";
+    let params = ReaderParams {
+        skip_header: Some(1),
+        names: Some(Names::Header),
+        ..Default::default()
+    };
+
+    let check = |err: ReaderError| match err {
+        ReaderError::FieldCountMismatch { line: 6, expected: 3, found: 2, .. } => {}
+        err => panic!("Unexpected error: {}", err),
+    };
+    check(load_bytes_i32(data.as_bytes(), &params).err().unwrap());
+    //Small buffers split up our "\r\n" line endings
+    for capacity in 1..8 {
+        check(load_reader_i32(BufReader::with_capacity(capacity, data.as_bytes()), &params).err().unwrap());
+        let rows = RowIter::<i32, _>::new(BufReader::with_capacity(capacity, data.as_bytes()), &params).unwrap();
+        check(rows.last().unwrap().err().unwrap());
+    }
+
+    //The same goes for a "\r\n" within a quoted field
+    let params = ReaderParams {
+        quote: Some(b'"'),
+        ..Default::default()
+    };
+    match load_bytes_string("\"a\r\nb\" c\r\nd e\r\nf\r\n".as_bytes(), &params).err().unwrap() {
+        ReaderError::FieldCountMismatch { line: 4, .. } => {}
+        err => panic!("Unexpected error: {}", err),
+    }
+}
+
+//The compressed versions of int_testv3.txt should give the same results as load_txt_i32_test2()
+#[cfg(any(feature = "gzip", feature = "zstd", feature = "bzip2", feature = "xz"))]
+fn load_txt_i32_compressed(file: &str) {
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        skip_footer: Some(1),
+        ..Default::default()
+    };
+
+    let results = load_txt_i32(file, &params);
+
+    assert_eq!(
+        *results.unwrap().get_results(),
+        vec![1, 2, 3, 4, 5, 6, 10, 11, 12, 13, 14, 15, 19, 20, 21, 25, 26, 27]
+    );
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn load_txt_i32_gzip_test() {
+    load_txt_i32_compressed("int_testv3.txt.gz");
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn load_txt_i32_zstd_test() {
+    load_txt_i32_compressed("int_testv3.txt.zst");
+}
+
+#[cfg(feature = "bzip2")]
+#[test]
+fn load_txt_i32_bzip2_test() {
+    load_txt_i32_compressed("int_testv3.txt.bz2");
+}
+
+//Plain text that happens to start with the same letters as bzip2's magic bytes isn't decompressed
+#[cfg(feature = "bzip2")]
+#[test]
+fn load_txt_bzip2_lookalike_test() {
+    let file = std::env::temp_dir().join("data_reader_bzip2_lookalike_test.txt");
+    let file = file.to_str().unwrap();
+    std::fs::write(file, "BZh 1 2\n3 4 5\n").unwrap();
+
+    let results = load_txt_string(file, &ReaderParams::default());
+    std::fs::remove_file(file).unwrap();
+
+    assert_eq!(*results.unwrap().get_results(), vec!["BZh", "1", "2", "3", "4", "5"]);
+}
+
+#[cfg(feature = "xz")]
+#[test]
+fn load_txt_i32_xz_test() {
+    load_txt_i32_compressed("int_testv3.txt.xz");
+}
+
+//Builds up a data set large enough that it gets split across several threads
+#[cfg(feature = "parallel")]
+fn par_test_data() -> String {
+    let mut data = String::from("% header comment\nx y z\n");
+    for i in 0..300_000 {
+        if i % 1000 == 0 {
+            data.push_str("   % a comment line that could end up anywhere\n");
+        }
+        data.push_str(&format!("{} {} {}\n", i, i + 1, i + 2));
+    }
+    data.push_str("sum total\n");
+    data
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn par_parse_bytes_test() {
+    let data = par_test_data();
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        skip_header: Some(1),
+        skip_footer: Some(1),
+        ..Default::default()
+    };
+
+    let seq = parse_bytes::<RawReaderResultsRows>(data.as_bytes(), &params).unwrap();
+    let par = pool.install(|| par_parse_bytes::<RawReaderResultsRows>(data.as_bytes(), &params)).unwrap();
+
+    assert_eq!(par.num_lines, 300_000);
+    assert_eq!(par.num_fields, seq.num_fields);
+    assert_eq!(par.results, seq.results);
+    assert_eq!(par.index, seq.index);
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        skip_header: Some(1),
+        skip_footer: Some(1),
+        usecols: Some(vec![0.into(), 2.into()]),
+        row_format: false,
+        ..Default::default()
+    };
+
+    let seq = parse_bytes::<RawReaderResultsCols>(data.as_bytes(), &params).unwrap();
+    let par = pool.install(|| par_parse_bytes::<RawReaderResultsCols>(data.as_bytes(), &params)).unwrap();
+
+    assert_eq!(par.num_lines, 300_000);
+    assert_eq!(par.results, seq.results);
+    assert_eq!(par.index, seq.index);
+}
+
+//Lines that only end with a carriage return still count towards the footer
+#[cfg(feature = "parallel")]
+#[test]
+fn par_parse_bytes_carriage_return_test() {
+    let data = par_test_data().replace('\n', "\r");
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        skip_header: Some(1),
+        skip_footer: Some(1),
+        ..Default::default()
+    };
+
+    let seq = parse_bytes::<RawReaderResultsRows>(data.as_bytes(), &params).unwrap();
+    let par = pool.install(|| par_parse_bytes::<RawReaderResultsRows>(data.as_bytes(), &params)).unwrap();
+
+    assert_eq!(par.num_lines, 300_000);
+    assert_eq!(par.results, seq.results);
+    assert_eq!(par.index, seq.index);
+}
+
+//A block of comments longer than a chunk means the first chunk has no lines of data to tell the rest how many fields they have
+#[cfg(feature = "parallel")]
+#[test]
+fn par_parse_bytes_comment_block_test() {
+    let mut data = String::new();
+    for _ in 0..40_000 {
+        data.push_str(&format!("% {}\n", "x".repeat(97)));
+    }
+    data.push_str("0 1 2\n");
+    for i in 1..300_000 {
+        data.push_str(&format!("{} {}\n", i, i + 1));
+    }
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        row_errors: RowErrors::Skip,
+        ..Default::default()
+    };
+
+    let seq = parse_bytes::<RawReaderResultsRows>(data.as_bytes(), &params).unwrap();
+    let par = pool.install(|| par_parse_bytes::<RawReaderResultsRows>(data.as_bytes(), &params)).unwrap();
+
+    assert_eq!(seq.num_lines, 1);
+    assert_eq!(par.num_lines, seq.num_lines);
+    assert_eq!(par.num_fields, 3);
+    assert_eq!(par.results, seq.results);
+}
+
+//The error from a chunk late in the data should still report the same line number as the serial parser
+#[cfg(feature = "parallel")]
+#[test]
+fn par_parse_bytes_error_test() {
+    let data = par_test_data().replace("\n250000 250001 250002\n", "\n250000 250001\n");
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        skip_header: Some(1),
+        skip_footer: Some(1),
+        ..Default::default()
+    };
+
+    let seq = parse_bytes::<RawReaderResultsRows>(data.as_bytes(), &params);
+    let par = pool.install(|| par_parse_bytes::<RawReaderResultsRows>(data.as_bytes(), &params));
+
+    assert_eq!(par.err().unwrap().to_string(), seq.err().unwrap().to_string());
+}
+
+//Our chunks need to count a "\r\n" as a single line just like the serial parser
+#[cfg(feature = "parallel")]
+#[test]
+fn par_parse_bytes_crlf_error_test() {
+    let data = par_test_data().replace("\n250000 250001 250002\n", "\n250000 250001\n").replace('\n', "\r\n");
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        skip_header: Some(1),
+        skip_footer: Some(1),
+        ..Default::default()
+    };
+
+    let seq = parse_bytes::<RawReaderResultsRows>(data.as_bytes(), &params);
+    let par = pool.install(|| par_parse_bytes::<RawReaderResultsRows>(data.as_bytes(), &params));
+
+    match seq.err().unwrap() {
+        ReaderError::FieldCountMismatch { line: 250254, .. } => {}
+        err => panic!("Unexpected error: {}", err),
+    }
+    match par.err().unwrap() {
+        ReaderError::FieldCountMismatch { line: 250254, .. } => {}
+        err => panic!("Unexpected error: {}", err),
+    }
+}
+
+//The row of a field that can't be converted should take into account all of the rows in the chunks before it
+#[cfg(feature = "parallel")]
+#[test]
+fn par_parse_bytes_conversion_error_test() {
+    let data = par_test_data().replace("\n250000 250001 250002\n", "\n250000 2500x1 250002\n");
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        skip_header: Some(1),
+        skip_footer: Some(1),
+        row_format: false,
+        ..Default::default()
+    };
+
+    let seq = parse_bytes::<TypedReaderResultsCols<f64>>(data.as_bytes(), &params).err().unwrap();
+    let par = pool.install(|| par_parse_bytes::<TypedReaderResultsCols<f64>>(data.as_bytes(), &params)).err().unwrap();
+
+    match &par {
+        ReaderError::Conversion { row, .. } => assert_eq!(*row, 250_000),
+        err => panic!("Unexpected error: {}", err),
+    }
+    assert_eq!(par.to_string(), seq.to_string());
+}
+
+//A table stitched back together from all of the chunks should be the same as the one from the serial parser
+#[cfg(feature = "parallel")]
+#[test]
+fn par_parse_bytes_table_test() {
+    let data = par_test_data();
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        skip_header: Some(1),
+        skip_footer: Some(1),
+        dtypes: Some(vec![DataType::I64, DataType::F64, DataType::String]),
+        ..Default::default()
+    };
+
+    let seq = parse_bytes::<Table>(data.as_bytes(), &params).unwrap();
+    let par = pool.install(|| par_parse_bytes::<Table>(data.as_bytes(), &params)).unwrap();
+
+    assert_eq!(par.num_lines, 300_000);
+    assert_eq!(par.columns, seq.columns);
+    assert_eq!(par.get_column(1).as_f64().unwrap()[299_999], 300_000.0);
+}
+
+//Skipped lines from all of the chunks should come back in order with the same information as the serial parser
+#[cfg(feature = "parallel")]
+#[test]
+fn par_parse_bytes_row_errors_test() {
+    let data = par_test_data()
+        .replace("\n250000 250001 250002\n", "\n250000 2500x1 250002\n")
+        .replace("\n1000 1001 1002\n", "\n1000 1001\n")
+        .replace("\n299999 300000 300001\n", "\n299999 300000 300001 1\n");
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+
+    let params = ReaderParams {
+        comments: Some(b'%'),
+        skip_header: Some(1),
+        skip_footer: Some(1),
+        row_errors: RowErrors::SkipAndReport,
+        ..Default::default()
+    };
+
+    let seq = parse_bytes::<TypedReaderResultsRows<i64>>(data.as_bytes(), &params).unwrap();
+    let par = pool.install(|| par_parse_bytes::<TypedReaderResultsRows<i64>>(data.as_bytes(), &params)).unwrap();
+
+    assert_eq!(par.num_lines, 300_000 - 3);
+    assert_eq!(par.results, seq.results);
+    assert_eq!(par.rejected.len(), 3);
+
+    for (par_rejected, seq_rejected) in par.rejected.iter().zip(seq.rejected.iter()) {
+        assert_eq!(par_rejected.line, seq_rejected.line);
+        assert_eq!(par_rejected.offset, seq_rejected.offset);
+        assert_eq!(par_rejected.bytes, seq_rejected.bytes);
+        assert_eq!(par_rejected.reason.to_string(), seq_rejected.reason.to_string());
+    }
+}